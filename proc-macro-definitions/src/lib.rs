@@ -0,0 +1,159 @@
+//! Procedural macro definitions for [tiptoe](https://github.com/Tamschi/tiptoe).
+//!
+//! Please enable `tiptoe`'s `"derive"` feature instead of depending on this crate directly.
+
+#![doc(html_root_url = "https://docs.rs/tiptoe_proc-macro-definitions/0.0.2")]
+#![warn(clippy::pedantic, missing_docs)]
+#![allow(clippy::semicolon_if_nothing_returned)]
+
+use proc_macro::TokenStream as TokenStream1;
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::{
+	parse_macro_input, spanned::Spanned, Data, DeriveInput, Error, Fields, Index, Member, Result,
+	Type,
+};
+
+/// See `tiptoe::IntrusivelyCountable` (with the `"derive"` feature enabled) for documentation.
+#[proc_macro_derive(IntrusivelyCountable, attributes(ref_counter))]
+pub fn derive_intrusively_countable(input: TokenStream1) -> TokenStream1 {
+	let input = parse_macro_input!(input as DeriveInput);
+	implement_intrusively_countable(&input)
+		.unwrap_or_else(|error| error.to_compile_error())
+		.into()
+}
+
+fn implement_intrusively_countable(input: &DeriveInput) -> Result<TokenStream> {
+	let name = &input.ident;
+	let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+	let (ref_counter_types, body) = match &input.data {
+		Data::Struct(data) => {
+			let (member, ref_counter_type) = find_ref_counter(&data.fields, name.span())?;
+			(vec![ref_counter_type], quote!(&self.#member))
+		}
+		Data::Enum(data) => {
+			if data.variants.is_empty() {
+				return Err(Error::new(
+					name.span(),
+					"`IntrusivelyCountable` can't be derived for enums without variants.",
+				));
+			}
+
+			let mut ref_counter_types = vec![];
+			let mut arms = vec![];
+			let mut errors: Option<Error> = None;
+			for variant in &data.variants {
+				match find_ref_counter(&variant.fields, variant.ident.span()) {
+					Ok((member, ref_counter_type)) => {
+						let variant = &variant.ident;
+						ref_counter_types.push(ref_counter_type);
+						arms.push(quote! {
+							Self::#variant { #member: ref_counter, .. } => ref_counter,
+						});
+					}
+					Err(error) => match &mut errors {
+						Some(errors) => errors.combine(error),
+						None => errors = Some(error),
+					},
+				}
+			}
+			if let Some(errors) = errors {
+				return Err(errors);
+			}
+
+			(ref_counter_types, quote!(match self { #(#arms)* }))
+		}
+		Data::Union(data) => {
+			return Err(Error::new(
+				data.union_token.span,
+				"`IntrusivelyCountable` can't be derived for unions.",
+			))
+		}
+	};
+
+	let ref_counter_type = ref_counter_types[0];
+	let ref_counter_assertions = ref_counter_types.iter().map(|ref_counter_type| {
+		quote_spanned! {ref_counter_type.span()=>
+			::tiptoe::__private::assert_ref_counter::<#ref_counter_type>();
+			::tiptoe::__private::assert_ref_counter_for::<#ref_counter_type, #name #type_generics>();
+		}
+	});
+
+	Ok(quote! {
+		unsafe impl #impl_generics ::tiptoe::IntrusivelyCountable for #name #type_generics #where_clause {
+			type RefCounter = #ref_counter_type;
+
+			#[allow(clippy::inline_always)]
+			#[inline(always)]
+			fn ref_counter(&self) -> &Self::RefCounter {
+				#body
+			}
+		}
+
+		const _: () = {
+			// The parameter provides implied bounds.
+			#[allow(dead_code)]
+			fn assert_safety_contract #impl_generics (_: &#name #type_generics) #where_clause {
+				#(#ref_counter_assertions)*
+
+				// This is ambiguous (and fails to compile) iff the annotated type is `Unpin`.
+				let _ = <#name #type_generics as ::tiptoe::__private::IntrusivelyCountableMustNotBeUnpin<_>>::assert;
+			}
+		};
+	})
+}
+
+/// Finds the single field marked `#[ref_counter]`.
+fn find_ref_counter(fields: &Fields, missing_span: Span) -> Result<(Member, &Type)> {
+	let mut found = None;
+	let mut errors: Option<Error> = None;
+	let mut push_error = |error| match &mut errors {
+		Some(errors) => errors.combine(error),
+		None => errors = Some(error),
+	};
+
+	for (i, field) in fields.iter().enumerate() {
+		for attribute in field
+			.attrs
+			.iter()
+			.filter(|attribute| attribute.path.is_ident("ref_counter"))
+		{
+			if !attribute.tokens.is_empty() {
+				push_error(Error::new_spanned(
+					&attribute.tokens,
+					"`#[ref_counter]` doesn't take arguments.",
+				))
+			}
+
+			if found.is_some() {
+				push_error(Error::new_spanned(
+					attribute,
+					"Only one field may be marked `#[ref_counter]`.",
+				))
+			} else {
+				let member = field.ident.clone().map_or_else(
+					|| {
+						Member::Unnamed(Index {
+							#[allow(clippy::cast_possible_truncation)]
+							index: i as u32,
+							span: field.ty.span(),
+						})
+					},
+					Member::Named,
+				);
+				found = Some((member, &field.ty));
+			}
+		}
+	}
+
+	if let Some(errors) = errors {
+		return Err(errors);
+	}
+	found.ok_or_else(|| {
+		Error::new(
+			missing_span,
+			"Expected a field marked `#[ref_counter]` here.",
+		)
+	})
+}
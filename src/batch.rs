@@ -0,0 +1,131 @@
+//! Batched decrements for [`Arc`]s that are dropped in tight loops.
+//!
+//! [`Arc::drop_batched`] and [`Arc::drop_batched_pinned`] don't decrement the reference count right away,
+//! but add the handle to a thread-local buffer instead, where handles to the same instance are coalesced.
+//! [`flush`] then decrements each instance's reference count once and drops the payloads whose count reached zero.
+//!
+//! The buffer is also flushed when it holds [`CAPACITY`] distinct instances, and when the thread exits.
+//!
+//! This is opt-in: Dropping an [`Arc`] normally still decrements its reference count immediately.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tiptoe::{batch, Arc, AtomicTipToe, IntrusivelyCountable};
+//!
+//! #[derive(Default)]
+//! struct Node {
+//!     counter: AtomicTipToe,
+//! }
+//!
+//! unsafe impl IntrusivelyCountable for Node {
+//!     type RefCounter = AtomicTipToe;
+//!
+//!     fn ref_counter(&self) -> &Self::RefCounter {
+//!         &self.counter
+//!     }
+//! }
+//!
+//! let node = Arc::new(Node::default());
+//! for temporary in Arc::clone_n(&node, 100) {
+//!     // ...
+//!     Arc::drop_batched(temporary);
+//! }
+//!
+//! // Decrements the reference count by 100 at once.
+//! batch::flush();
+//! ```
+
+use crate::{Arc, IntrusivelyCountable};
+use alloc::vec::Vec;
+use allocator_api2::alloc::Global;
+use core::{cell::RefCell, mem, ptr::NonNull};
+use std::thread_local;
+
+/// The number of distinct instances the buffer holds before it is flushed automatically.
+pub const CAPACITY: usize = 64;
+
+/// Strong references to one instance, which are given up together.
+struct Pending {
+	pointer: NonNull<()>,
+	count: usize,
+	release: unsafe fn(NonNull<()>, usize),
+}
+
+struct Buffer(RefCell<Vec<Pending>>);
+
+thread_local! {
+	static BUFFER: Buffer = const { Buffer(RefCell::new(Vec::new())) };
+}
+
+impl Buffer {
+	/// Adds a strong reference to `pointer` and returns whether the buffer is full now.
+	fn push(&self, pointer: NonNull<()>, release: unsafe fn(NonNull<()>, usize)) -> bool {
+		let mut buffer = self.0.borrow_mut();
+		match buffer
+			.iter_mut()
+			.rev()
+			.find(|pending| pending.pointer == pointer)
+		{
+			Some(pending) => pending.count += 1,
+			None => buffer.push(Pending {
+				pointer,
+				count: 1,
+				release,
+			}),
+		}
+		buffer.len() >= CAPACITY
+	}
+
+	fn flush(&self) {
+		loop {
+			let mut pending = {
+				let mut buffer = self.0.borrow_mut();
+				if buffer.is_empty() {
+					return;
+				}
+				mem::take(&mut *buffer)
+			};
+
+			// Dropped payloads may batch further handles.
+			for pending in pending.drain(..) {
+				unsafe { (pending.release)(pending.pointer, pending.count) }
+			}
+
+			// Keeps the capacity.
+			let mut buffer = self.0.borrow_mut();
+			if buffer.is_empty() {
+				*buffer = pending;
+			}
+		}
+	}
+}
+
+impl Drop for Buffer {
+	fn drop(&mut self) {
+		self.flush()
+	}
+}
+
+/// Gives up the current thread's batched strong references,
+/// dropping each payload whose reference count reaches zero.
+///
+/// Handles that are batched while this runs (e.g. by dropped payloads) are given up too.
+pub fn flush() {
+	BUFFER.try_with(Buffer::flush).ok();
+}
+
+/// Adds `arc`'s strong reference to the current thread's buffer, or drops it right away while the thread exits.
+pub(crate) fn push<T: IntrusivelyCountable + 'static>(arc: Arc<T>) {
+	unsafe fn release<T: IntrusivelyCountable>(pointer: NonNull<()>, count: usize) {
+		Arc::drop_n(pointer.cast::<T>(), count, &Global)
+	}
+
+	let pointer = Arc::leak(arc);
+	let full = BUFFER.try_with(|buffer| buffer.push(pointer.cast(), release::<T>));
+	match full {
+		Ok(true) => flush(),
+		Ok(false) => (),
+		Err(_) => drop(unsafe { Arc::from_raw(pointer) }),
+	}
+}
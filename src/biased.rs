@@ -0,0 +1,195 @@
+//! Owner thread bookkeeping for [`BiasedTipToe`](`crate::BiasedTipToe`).
+
+use crate::{sync::Arc, IntrusivelyCountable};
+use alloc::{boxed::Box, vec::Vec};
+use core::{
+	cell::Cell,
+	mem,
+	ptr::NonNull,
+	sync::atomic::{AtomicBool, Ordering},
+};
+use std::{
+	sync::{Mutex, MutexGuard, PoisonError},
+	thread_local,
+};
+
+/// A thread's registration as owner of biased reference counts.
+///
+/// Registrations are never freed, but are recycled once their thread exits.
+pub(crate) struct Owner {
+	state: Mutex<State>,
+	/// Whether [`State::deferred`] may be non-empty, so that the owner thread can check cheaply.
+	pending: AtomicBool,
+}
+
+pub(crate) struct State {
+	/// Whether the owner thread exited, so that other threads may merge its shares.
+	exited: bool,
+	/// Strong handles that other threads couldn't drop themselves.
+	deferred: Vec<Deferred>,
+}
+
+/// A type-erased [`Arc`] handed to its owner thread.
+struct Deferred {
+	handle: NonNull<()>,
+	drop: unsafe fn(NonNull<()>),
+}
+
+/// Other threads can only hold an [`Arc`] whose payload is [`Send`] and [`Sync`] (barring `unsafe` code),
+/// so sending it back to the owner thread is fine.
+unsafe impl Send for Deferred {}
+
+/// Registrations of exited threads.
+static RECYCLED: Mutex<Vec<&'static Owner>> = Mutex::new(Vec::new());
+
+thread_local! {
+	static CURRENT: Registration = const { Registration(Cell::new(None)) };
+}
+
+struct Registration(Cell<Option<&'static Owner>>);
+
+impl Drop for Registration {
+	fn drop(&mut self) {
+		if let Some(owner) = self.0.get() {
+			owner.exit()
+		}
+	}
+}
+
+impl Owner {
+	/// The current thread's registration, if any.
+	///
+	/// This is [`None`] while the thread's registration is being torn down.
+	pub(crate) fn current() -> Option<&'static Self> {
+		CURRENT.try_with(|current| current.0.get()).ok().flatten()
+	}
+
+	/// The current thread's registration, which is created if necessary.
+	///
+	/// This is [`None`] while the thread's registration is being torn down.
+	pub(crate) fn register() -> Option<&'static Self> {
+		CURRENT
+			.try_with(|current| {
+				current.0.get().unwrap_or_else(|| {
+					let owner = match lock(&RECYCLED).pop() {
+						Some(owner) => {
+							owner.lock().exited = false;
+							owner
+						}
+						None => Box::leak(Box::new(Self {
+							state: Mutex::new(State {
+								exited: false,
+								deferred: Vec::new(),
+							}),
+							pending: AtomicBool::new(false),
+						})),
+					};
+					current.0.set(Some(owner));
+					owner
+				})
+			})
+			.ok()
+	}
+
+	/// Locks this registration.
+	///
+	/// While the owner thread hasn't exited, holding the lock doesn't exclude it from counting.
+	pub(crate) fn lock(&self) -> MutexGuard<'_, State> {
+		lock(&self.state)
+	}
+
+	/// Drops the handles deferred to the current thread so far.
+	pub(crate) fn drop_deferred() {
+		if let Some(owner) = Self::current() {
+			owner.drop_queue()
+		}
+	}
+
+	/// Like [`Owner::drop_deferred`], but only checks an atomic flag unless handles were deferred to the current thread.
+	pub(crate) fn drop_pending() {
+		if let Some(owner) = Self::current() {
+			if owner.pending.load(Ordering::Relaxed) {
+				owner.drop_queue()
+			}
+		}
+	}
+
+	fn drop_queue(&self) {
+		let deferred = {
+			let mut state = self.lock();
+			self.pending.store(false, Ordering::Relaxed);
+			mem::take(&mut state.deferred)
+		};
+		drop_all(deferred)
+	}
+
+	fn exit(&'static self) {
+		let deferred = {
+			let mut state = self.lock();
+			state.exited = true;
+			self.pending.store(false, Ordering::Relaxed);
+			mem::take(&mut state.deferred)
+		};
+
+		// The current thread isn't registered anymore, so these merge their owner share if it was counted here.
+		drop_all(deferred);
+		lock(&RECYCLED).push(self);
+	}
+}
+
+impl State {
+	pub(crate) fn exited(&self) -> bool {
+		self.exited
+	}
+}
+
+/// A strong reference that has to be handed to the owner thread of its counter.
+pub struct Deferral {
+	owner: &'static Owner,
+}
+
+impl Deferral {
+	pub(crate) fn new(owner: &'static Owner) -> Self {
+		Self { owner }
+	}
+
+	/// Hands the strong reference to `pointer` to the owner thread, which drops it later through an [`Arc`].
+	///
+	/// Returns `false` without taking the reference iff the owner thread exited in the meantime,
+	/// in which case the caller should decrement again.
+	///
+	/// # Safety
+	///
+	/// `pointer` must be a strong reference that is compatible with [`Arc::from_raw`].
+	pub(crate) unsafe fn defer<T: ?Sized + IntrusivelyCountable>(
+		self,
+		pointer: NonNull<T>,
+	) -> bool {
+		unsafe fn drop_arc<T: ?Sized + IntrusivelyCountable>(handle: NonNull<()>) {
+			let pointer = *Box::from_raw(handle.cast::<NonNull<T>>().as_ptr());
+			drop(Arc::from_raw(pointer))
+		}
+
+		let mut state = self.owner.lock();
+		if state.exited {
+			return false;
+		}
+		state.deferred.push(Deferred {
+			handle: NonNull::from(Box::leak(Box::new(pointer))).cast(),
+			drop: drop_arc::<T>,
+		});
+		self.owner.pending.store(true, Ordering::Relaxed);
+		true
+	}
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+	// No user code runs while these are locked.
+	mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn drop_all(deferred: Vec<Deferred>) {
+	for deferred in deferred {
+		unsafe { (deferred.drop)(deferred.handle) }
+	}
+}
@@ -0,0 +1,549 @@
+//! A generic counted header for payloads that can't embed a reference counter themselves,
+//! most importantly dynamically sized slices and string slices.
+
+use crate::{AllocError, IntrusivelyCountable, RefCounter, RefCounterFor, TipToe};
+use alloc::{
+	alloc::{alloc, dealloc, handle_alloc_error},
+	boxed::Box,
+	vec::Vec,
+};
+#[cfg(feature = "sync")]
+use core::ptr::NonNull;
+use core::{
+	alloc::Layout,
+	borrow::Borrow,
+	cmp,
+	fmt::{self, Debug, Display, Formatter},
+	hash::{Hash, Hasher},
+	iter::FromIterator,
+	mem,
+	ops::Deref,
+	ptr,
+};
+
+/// A reference counter `C` followed by a (possibly dynamically sized) `value`.
+///
+/// This makes any type, including `[T]` and [`str`], [`IntrusivelyCountable`].
+/// Dynamically sized instances are constructed through [`From`] and [`FromIterator`] conversions into
+/// [`Box`], [`Rc`](`crate::Rc`) or [`Arc`](`crate::Arc`), which allocate only once where possible.
+///
+/// Transparent to [`Debug`], [`Display`], [`PartialEq`], [`Eq`], [`PartialOrd`], [`Ord`] and [`Hash`].
+///
+/// # Example
+///
+/// ```rust
+/// use tiptoe::{counted::Counted, Rc};
+///
+/// let slice: Rc<Counted<[u8]>> = vec![1, 2, 3].into();
+/// let string: Rc<Counted<str>> = "Hello!".into();
+///
+/// assert_eq!(&**slice, &[1, 2, 3]);
+/// assert_eq!(&**string, "Hello!");
+/// ```
+#[repr(C)]
+pub struct Counted<T: ?Sized, C = TipToe> {
+	counter: C,
+	value: T,
+}
+
+unsafe impl<T: ?Sized, C: RefCounterFor<T>> IntrusivelyCountable for Counted<T, C> {
+	type RefCounter = C;
+
+	fn ref_counter(&self) -> &Self::RefCounter {
+		#![allow(clippy::inline_always)]
+		#![inline(always)]
+		&self.counter
+	}
+}
+
+impl<T, C: RefCounter + Default> Counted<T, C> {
+	/// Creates a new instance of [`Counted`] with a fresh reference counter.
+	#[must_use]
+	pub fn new(value: T) -> Self {
+		Self {
+			counter: C::default(),
+			value,
+		}
+	}
+
+	/// Unwraps the payload, discarding the reference counter.
+	#[must_use]
+	pub fn into_inner(this: Self) -> T {
+		this.value
+	}
+}
+
+impl<T, C: RefCounter + Default> Counted<[T], C> {
+	/// Allocates a new instance with a fresh reference counter, moving the items of `items` into it,
+	/// without aborting if the allocation fails.
+	///
+	/// # Errors
+	///
+	/// Iff the allocation fails, in which case the unconsumed iterator is handed back through the [`AllocError`].
+	///
+	/// # Panics
+	///
+	/// Iff `items` yields fewer items than its [`ExactSizeIterator::len`] or the layout overflows.
+	pub fn try_from_exact_iter<I>(items: I) -> Result<Box<Self>, AllocError<I::IntoIter>>
+	where
+		I: IntoIterator<Item = T>,
+		I::IntoIter: ExactSizeIterator,
+	{
+		let items = items.into_iter();
+		Self::try_from_len_and_iter(items.len(), items)
+	}
+
+	/// Allocates a new instance with a fresh reference counter,
+	/// filled with exactly `len` items from `values`.
+	///
+	/// # Panics
+	///
+	/// Iff `values` yields fewer than `len` items or the layout overflows.
+	fn from_exact_iter(len: usize, values: impl Iterator<Item = T>) -> Box<Self> {
+		Self::try_from_len_and_iter(len, values)
+			.unwrap_or_else(|_| handle_alloc_error(Self::layout(len)))
+	}
+
+	/// Allocates a new instance with a fresh reference counter for the `len` items that `values` announced,
+	/// but collects them into a [`Vec`] first instead iff `values` yields a different number of items.
+	///
+	/// # Panics
+	///
+	/// Iff the layout overflows.
+	fn from_announced_iter(len: usize, mut values: impl Iterator<Item = T>) -> Box<Self> {
+		let layout = Self::layout(len);
+		unsafe {
+			let mut guard = Guard::allocate(layout, |memory| {
+				ptr::addr_of_mut!((*Self::pointer(memory, len)).value).cast::<T>()
+			})
+			.unwrap_or_else(|| handle_alloc_error(layout));
+			guard.fill(values.by_ref().take(len));
+
+			let extra = if guard.initialized == len {
+				match values.next() {
+					None => {
+						let pointer = Self::pointer(guard.finish(), len);
+						ptr::addr_of_mut!((*pointer).counter).write(C::default());
+						return Box::from_raw(pointer);
+					}
+					Some(extra) => Some(extra),
+				}
+			} else {
+				None
+			};
+
+			let mut vec = guard.into_vec(usize::from(extra.is_some()) + values.size_hint().0);
+			vec.extend(extra);
+			vec.extend(values);
+			vec.into()
+		}
+	}
+
+	/// Like [`Self::from_exact_iter`], but hands back `values` iff the allocation fails.
+	fn try_from_len_and_iter<I: Iterator<Item = T>>(
+		len: usize,
+		values: I,
+	) -> Result<Box<Self>, AllocError<I>> {
+		unsafe {
+			let memory = allocate_with_items(Self::layout(len), len, values, |memory| {
+				ptr::addr_of_mut!((*Self::pointer(memory, len)).value).cast::<T>()
+			})
+			.map_err(|values| AllocError { value: values })?;
+			let pointer = Self::pointer(memory, len);
+			ptr::addr_of_mut!((*pointer).counter).write(C::default());
+			Ok(Box::from_raw(pointer))
+		}
+	}
+
+	/// Calculates the (padded) layout of an instance with `len` items.
+	///
+	/// # Panics
+	///
+	/// Iff the layout overflows.
+	fn layout(len: usize) -> Layout {
+		let (layout, _) = Layout::new::<C>()
+			.extend(Layout::array::<T>(len).expect("Layout overflow."))
+			.expect("Layout overflow.");
+		layout.pad_to_align()
+	}
+
+	/// Reinterprets `memory` as pointer to an instance with `len` items.
+	fn pointer(memory: *mut u8, len: usize) -> *mut Self {
+		ptr::slice_from_raw_parts_mut(memory.cast::<T>(), len) as *mut Self
+	}
+}
+
+impl<H, T, C: RefCounter + Default> Counted<HeaderSlice<H, [T]>, C> {
+	/// Allocates a new instance with a fresh reference counter, moving `header` and the items of `items` into it.
+	///
+	/// # Panics
+	///
+	/// Iff `items` yields fewer items than its [`ExactSizeIterator::len`] or the layout overflows.
+	pub fn from_header_and_iter<I>(header: H, items: I) -> Box<Self>
+	where
+		I: IntoIterator<Item = T>,
+		I::IntoIter: ExactSizeIterator,
+	{
+		let items = items.into_iter();
+		let len = items.len();
+		Self::try_from_header_and_iter(header, items)
+			.unwrap_or_else(|_| handle_alloc_error(Self::layout(len)))
+	}
+
+	/// Allocates a new instance with a fresh reference counter, moving `header` and the items of `items` into it,
+	/// without aborting if the allocation fails.
+	///
+	/// # Errors
+	///
+	/// Iff the allocation fails, in which case `header` and the unconsumed iterator are handed back through the [`AllocError`].
+	///
+	/// # Panics
+	///
+	/// Iff `items` yields fewer items than its [`ExactSizeIterator::len`] or the layout overflows.
+	pub fn try_from_header_and_iter<I>(
+		header: H,
+		items: I,
+	) -> Result<Box<Self>, AllocError<(H, I::IntoIter)>>
+	where
+		I: IntoIterator<Item = T>,
+		I::IntoIter: ExactSizeIterator,
+	{
+		let items = items.into_iter();
+		let len = items.len();
+
+		unsafe {
+			let memory = match allocate_with_items(Self::layout(len), len, items, |memory| {
+				ptr::addr_of_mut!((*Self::pointer(memory, len)).value.slice).cast::<T>()
+			}) {
+				Ok(memory) => memory,
+				Err(items) => {
+					return Err(AllocError {
+						value: (header, items),
+					})
+				}
+			};
+			let pointer = Self::pointer(memory, len);
+			ptr::addr_of_mut!((*pointer).counter).write(C::default());
+			ptr::addr_of_mut!((*pointer).value.header).write(header);
+			ptr::addr_of_mut!((*pointer).value.length).write(len);
+			Ok(Box::from_raw(pointer))
+		}
+	}
+
+	/// Calculates the (padded) layout of an instance with `len` items.
+	///
+	/// # Panics
+	///
+	/// Iff the layout overflows.
+	fn layout(len: usize) -> Layout {
+		let (header_slice, _) = Layout::new::<H>()
+			.extend(Layout::new::<usize>())
+			.and_then(|(layout, _)| layout.extend(Layout::array::<T>(len)?))
+			.expect("Layout overflow.");
+		let (layout, _) = Layout::new::<C>()
+			.extend(header_slice.pad_to_align())
+			.expect("Layout overflow.");
+		layout.pad_to_align()
+	}
+}
+
+impl<H, T, C> Counted<HeaderSlice<H, [T]>, C> {
+	/// Reconstructs the full pointer from `thin`, using the stored length.
+	///
+	/// # Safety
+	///
+	/// `thin` must point to a valid instance of [`Self`].
+	#[cfg(feature = "sync")]
+	pub(crate) unsafe fn from_thin(
+		thin: NonNull<Counted<HeaderSlice<H, [T; 0]>, C>>,
+	) -> NonNull<Self> {
+		let len = ptr::addr_of!((*thin.as_ptr()).value.length).read();
+		NonNull::new_unchecked(Self::pointer(thin.as_ptr().cast(), len))
+	}
+
+	/// Reinterprets `memory` as pointer to an instance with `len` items.
+	fn pointer(memory: *mut u8, len: usize) -> *mut Self {
+		ptr::slice_from_raw_parts_mut(memory.cast::<T>(), len) as *mut Self
+	}
+}
+
+/// Allocates memory for `layout` and moves exactly `len` items from `values` into it,
+/// at the location returned by `elements`.
+///
+/// # Errors
+///
+/// Iff the allocation fails, in which case `values` is handed back unconsumed.
+///
+/// # Panics
+///
+/// Iff `values` yields fewer than `len` items.
+/// The items moved so far are dropped and the allocation is freed in that case.
+///
+/// # Safety
+///
+/// `layout` must not be zero-sized and `elements` must return a suitably aligned location with space for `len` items.
+unsafe fn allocate_with_items<T, I: Iterator<Item = T>>(
+	layout: Layout,
+	len: usize,
+	values: I,
+	elements: impl FnOnce(*mut u8) -> *mut T,
+) -> Result<*mut u8, I> {
+	match Guard::allocate(layout, elements) {
+		Some(mut guard) => {
+			guard.fill(values.take(len));
+			assert_eq!(
+				guard.initialized, len,
+				"Iterator yielded fewer items than announced."
+			);
+			Ok(guard.finish())
+		}
+		None => Err(values),
+	}
+}
+
+/// An allocation that is filled with items, which drops them and frees the allocation unless [finished](`Guard::finish`),
+/// e.g. on panic.
+struct Guard<T> {
+	memory: *mut u8,
+	layout: Layout,
+	elements: *mut T,
+	initialized: usize,
+}
+
+impl<T> Guard<T> {
+	/// # Safety
+	///
+	/// See [`allocate_with_items`].
+	unsafe fn allocate(layout: Layout, elements: impl FnOnce(*mut u8) -> *mut T) -> Option<Self> {
+		let memory = alloc(layout);
+		(!memory.is_null()).then(|| Self {
+			memory,
+			layout,
+			elements: elements(memory),
+			initialized: 0,
+		})
+	}
+
+	/// Moves all of `values` into the allocation.
+	///
+	/// # Safety
+	///
+	/// The allocation must have space for them.
+	unsafe fn fill(&mut self, values: impl Iterator<Item = T>) {
+		for value in values {
+			self.elements.add(self.initialized).write(value);
+			self.initialized += 1;
+		}
+	}
+
+	/// Moves the items out into a [`Vec`] with space for `additional` more and frees the allocation.
+	fn into_vec(mut self, additional: usize) -> Vec<T> {
+		let mut vec = Vec::with_capacity(self.initialized.saturating_add(additional));
+		unsafe {
+			ptr::copy_nonoverlapping(self.elements, vec.as_mut_ptr(), self.initialized);
+			vec.set_len(self.initialized);
+		}
+		self.initialized = 0;
+		vec
+	}
+
+	/// Keeps the items and returns the allocation.
+	fn finish(self) -> *mut u8 {
+		let memory = self.memory;
+		mem::forget(self);
+		memory
+	}
+}
+
+impl<T> Drop for Guard<T> {
+	fn drop(&mut self) {
+		unsafe {
+			ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
+				self.elements,
+				self.initialized,
+			));
+			dealloc(self.memory, self.layout);
+		}
+	}
+}
+
+/// A `header` followed by a `slice` whose length is stored inline,
+/// so that [`ThinArc`](`crate::sync::ThinArc`) can point to it with a thin pointer.
+///
+/// Create instances through [`Counted::from_header_and_iter`].
+#[repr(C)]
+pub struct HeaderSlice<H, S: ?Sized> {
+	/// The fixed-size header.
+	pub header: H,
+	/// Always equal to `slice.len()`.
+	length: usize,
+	/// The dynamically sized tail.
+	pub slice: S,
+}
+
+impl<H, S: ?Sized> Debug for HeaderSlice<H, S>
+where
+	H: Debug,
+	S: Debug,
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.debug_struct("HeaderSlice")
+			.field("header", &self.header)
+			.field("slice", &&self.slice)
+			.finish_non_exhaustive()
+	}
+}
+
+impl<H, S: ?Sized> Eq for HeaderSlice<H, S>
+where
+	H: Eq,
+	S: Eq,
+{
+}
+
+impl<H, S: ?Sized> Hash for HeaderSlice<H, S>
+where
+	H: Hash,
+	S: Hash,
+{
+	fn hash<X: Hasher>(&self, state: &mut X) {
+		self.header.hash(state);
+		self.slice.hash(state);
+	}
+}
+
+impl<H, S: ?Sized> PartialEq for HeaderSlice<H, S>
+where
+	H: PartialEq,
+	S: PartialEq,
+{
+	fn eq(&self, other: &Self) -> bool {
+		self.header == other.header && self.slice == other.slice
+	}
+}
+
+impl<T: ?Sized, C> AsRef<T> for Counted<T, C> {
+	fn as_ref(&self) -> &T {
+		&self.value
+	}
+}
+
+impl<T: ?Sized, C> Borrow<T> for Counted<T, C> {
+	fn borrow(&self) -> &T {
+		&self.value
+	}
+}
+
+impl<T: Clone, C: RefCounter + Default> Clone for Counted<T, C> {
+	/// Clones the payload, with a fresh reference counter.
+	fn clone(&self) -> Self {
+		Self::new(self.value.clone())
+	}
+}
+
+impl<T: ?Sized, C> Debug for Counted<T, C>
+where
+	T: Debug,
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		self.value.fmt(f)
+	}
+}
+
+impl<T, C: RefCounter + Default> Default for Counted<T, C>
+where
+	T: Default,
+{
+	fn default() -> Self {
+		Self::new(T::default())
+	}
+}
+
+impl<T: ?Sized, C> Deref for Counted<T, C> {
+	type Target = T;
+
+	fn deref(&self) -> &Self::Target {
+		&self.value
+	}
+}
+
+impl<T: ?Sized, C> Display for Counted<T, C>
+where
+	T: Display,
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		self.value.fmt(f)
+	}
+}
+
+impl<T: ?Sized, C> Eq for Counted<T, C> where T: Eq {}
+
+impl<T, C: RefCounter + Default> From<Vec<T>> for Box<Counted<[T], C>> {
+	/// Moves the items of `vec` into a new allocation.
+	fn from(vec: Vec<T>) -> Self {
+		Counted::from_exact_iter(vec.len(), vec.into_iter())
+	}
+}
+
+impl<T: Clone, C: RefCounter + Default> From<&[T]> for Box<Counted<[T], C>> {
+	fn from(slice: &[T]) -> Self {
+		Counted::from_exact_iter(slice.len(), slice.iter().cloned())
+	}
+}
+
+impl<C: RefCounter + Default> From<&str> for Box<Counted<str, C>> {
+	fn from(str: &str) -> Self {
+		let bytes = Box::<Counted<[u8], C>>::from(str.as_bytes());
+		unsafe { Box::from_raw(Box::into_raw(bytes) as *mut Counted<str, C>) }
+	}
+}
+
+impl<T, C: RefCounter + Default> FromIterator<T> for Box<Counted<[T], C>> {
+	/// Allocates only once iff `iter`'s [size hint](`Iterator::size_hint`) is exact.
+	///
+	/// Otherwise, or if `iter` then yields a different number of items than announced,
+	/// the items are collected into a [`Vec`] first.
+	fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+		let iter = iter.into_iter();
+		match iter.size_hint() {
+			(lower, Some(upper)) if lower == upper => Counted::from_announced_iter(lower, iter),
+			_ => iter.collect::<Vec<_>>().into(),
+		}
+	}
+}
+
+impl<T: ?Sized, C> Hash for Counted<T, C>
+where
+	T: Hash,
+{
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.value.hash(state)
+	}
+}
+
+impl<T: ?Sized, C> Ord for Counted<T, C>
+where
+	T: Ord,
+{
+	fn cmp(&self, other: &Self) -> cmp::Ordering {
+		self.value.cmp(&other.value)
+	}
+}
+
+impl<T: ?Sized, C, O: ?Sized, D> PartialEq<Counted<O, D>> for Counted<T, C>
+where
+	T: PartialEq<O>,
+{
+	fn eq(&self, other: &Counted<O, D>) -> bool {
+		self.value == other.value
+	}
+}
+
+impl<T: ?Sized, C, O: ?Sized, D> PartialOrd<Counted<O, D>> for Counted<T, C>
+where
+	T: PartialOrd<O>,
+{
+	fn partial_cmp(&self, other: &Counted<O, D>) -> Option<cmp::Ordering> {
+		self.value.partial_cmp(&other.value)
+	}
+}
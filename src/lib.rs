@@ -10,9 +10,33 @@
 //!
 //! # Features
 //!
+//! ## `"derive"`
+//!
+//! Enables [`#[derive(IntrusivelyCountable)]`](macro@IntrusivelyCountable).
+//!
 //! ## `"sync"`
 //!
-//! Enables the [`Arc`] type, which requires [`AtomicUsize`](`core::sync::atomic::AtomicUsize`).
+//! Enables the [`Arc`] type and the [`AtomicTipToe`] and [`AtomicTipToes`] reference counters,
+//! which require [`AtomicUsize`](`core::sync::atomic::AtomicUsize`).
+//!
+//! [`Arc`] supports custom allocators through [allocator-api2](https://crates.io/crates/allocator-api2).
+//! The [`pool`] module contains an allocator that recycles allocations.
+//!
+//! This feature is purely additive:
+//! [`TipToe`] and [`TipToes`] are never atomic and can be used alongside the atomic counters.
+//!
+//! ## `"std"`
+//!
+//! Implies `"sync"` and enables the [`BiasedTipToe`] and [`ShardedTipToe`] reference counters
+//! as well as the [`batch`] module, which need thread-locals
+//! (and a [`Mutex`](`std::sync::Mutex`) to keep track of [`BiasedTipToe`]'s owner thread).
+//!
+//! ## `"nightly"`
+//!
+//! Implements `CoerceUnsized` for [`Rc`] and [`Arc`], so that they coerce to e.g. `Rc<dyn Trait>` implicitly.
+//! This requires a nightly compiler.
+//!
+//! On stable Rust, use the [`unsize!`] macro instead.
 //!
 //! # Example
 //!
@@ -47,35 +71,320 @@
 //! but very helpful if a struct should still be otherwise(!) mutable behind an intrusive pointer.
 //!
 //! Note that `A` must not be [`Unpin`] (in a way that would interfere with reference-counting).
+//!
+//! With the `"derive"` feature, [`#[derive(IntrusivelyCountable)]`](macro@IntrusivelyCountable) can generate this implementation
+//! and check parts of its safety contract at compile-time.
 
 #![doc(html_root_url = "https://docs.rs/tiptoe/0.0.2")]
 #![warn(clippy::pedantic, missing_docs)]
 #![allow(clippy::semicolon_if_nothing_returned)]
 #![no_std]
+#![cfg_attr(
+	feature = "nightly",
+	feature(coerce_unsized, pin_coerce_unsized_trait, unsize)
+)]
 
 #[cfg(doctest)]
 #[doc = include_str!("../README.md")]
 mod readme {}
 
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
-#[cfg(not(feature = "sync"))]
-use core::cell::Cell;
 #[cfg(feature = "sync")]
 use core::sync::atomic::AtomicUsize;
 use core::{
+	any::{Any, TypeId},
+	cell::Cell,
 	cmp,
+	fmt::{self, Debug, Display, Formatter},
 	hash::Hash,
 	marker::PhantomPinned,
-	mem::ManuallyDrop,
+	mem::{self, ManuallyDrop},
 	ops::{Deref, DerefMut},
 	pin::Pin,
+	ptr,
+	sync::atomic::{AtomicPtr, Ordering},
 };
 
+#[cfg(feature = "std")]
+pub mod batch;
+#[cfg(feature = "std")]
+mod biased;
+pub mod counted;
+pub mod rc;
+#[cfg(feature = "std")]
+mod sharded;
+pub use rc::Rc;
+
 #[cfg(feature = "sync")]
-mod sync;
+pub mod pool;
 #[cfg(feature = "sync")]
-pub use sync::Arc;
+pub mod sync;
+#[cfg(feature = "sync")]
+pub use sync::{Arc, ArcBorrow, MappedArc, SharedFromThis, UniqueArc};
+
+/// Derives [`IntrusivelyCountable`](trait@IntrusivelyCountable) for a struct or enum that embeds its reference counter.
+///
+/// Mark the counter field with `#[ref_counter]`.
+/// In enums, each variant must have exactly one such field, and they must all be of the same type.
+///
+/// The generated [`IntrusivelyCountable::ref_counter`] is `#[inline(always)]`.
+///
+/// # Example
+///
+/// ```rust
+/// use tiptoe::{IntrusivelyCountable, Rc, TipToe};
+///
+/// #[derive(IntrusivelyCountable)]
+/// struct A<T> {
+///     value: T,
+///     #[ref_counter]
+///     counter: TipToe,
+/// }
+///
+/// #[derive(IntrusivelyCountable)]
+/// enum B {
+///     Tuple(#[ref_counter] TipToe, usize),
+///     Struct {
+///         #[ref_counter]
+///         counter: TipToe,
+///     },
+/// }
+///
+/// let a = Rc::pin(A { value: 1, counter: TipToe::new() });
+/// let b = Rc::pin(B::Tuple(TipToe::new(), 2));
+/// ```
+///
+/// # Compile-time checks
+///
+/// The [`IntrusivelyCountable`](trait@IntrusivelyCountable) safety contract is partially checked.
+///
+/// There must be exactly one counter field (per variant):
+///
+/// ```compile_fail
+/// # use tiptoe::{IntrusivelyCountable, TipToe};
+/// #[derive(IntrusivelyCountable)]
+/// struct A {
+///     counter: TipToe,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// # use tiptoe::{IntrusivelyCountable, TipToe};
+/// #[derive(IntrusivelyCountable)]
+/// struct A {
+///     #[ref_counter]
+///     counter: TipToe,
+///     #[ref_counter]
+///     other_counter: TipToe,
+/// }
+/// ```
+///
+/// The counter must be a [`RefCounter`]:
+///
+/// ```compile_fail
+/// # use core::cell::Cell;
+/// # use tiptoe::IntrusivelyCountable;
+/// #[derive(IntrusivelyCountable)]
+/// struct A {
+///     #[ref_counter]
+///     counter: Cell<usize>,
+/// }
+/// ```
+///
+/// The annotated type must not be [`Unpin`]
+/// (which shows up as type inference error mentioning `IntrusivelyCountableMustNotBeUnpin`):
+///
+/// ```compile_fail
+/// # use tiptoe::{IntrusivelyCountable, TipToe};
+/// #[derive(IntrusivelyCountable)]
+/// struct A {
+///     #[ref_counter]
+///     counter: TipToe,
+/// }
+///
+/// impl Unpin for A {}
+/// ```
+///
+/// With [`BiasedTipToe`], the annotated type must be `'static`:
+///
+/// ```compile_fail
+/// # use tiptoe::{BiasedTipToe, IntrusivelyCountable};
+/// #[derive(IntrusivelyCountable)]
+/// struct A<'a> {
+///     #[ref_counter]
+///     counter: BiasedTipToe,
+///     value: &'a str,
+/// }
+/// ```
+///
+/// Whether the counter is otherwise accessed isn't checked.
+#[cfg(feature = "derive")]
+pub use tiptoe_proc_macro_definitions::IntrusivelyCountable;
+
+/// Safely converts an [`Rc`] or [`Arc`] (or a [`Pin`] of either) into one of a dynamically sized type, like `dyn Trait`.
+///
+/// The target type must be [`IntrusivelyCountable`](trait@IntrusivelyCountable) with the same [`RefCounter`] type,
+/// which for trait objects is most easily achieved with a supertrait.
+///
+/// This uses an unsizing coercion, so only valid conversions compile.
+/// With the `"nightly"` feature, these coercions also happen implicitly.
+///
+/// # Example
+///
+/// ```rust
+/// use core::pin::Pin;
+/// use tiptoe::{unsize, IntrusivelyCountable, Rc, TipToe};
+///
+/// trait Shape: IntrusivelyCountable<RefCounter = TipToe> {
+///     fn area(&self) -> f64;
+/// }
+///
+/// struct Square {
+///     counter: TipToe,
+///     side: f64,
+/// }
+///
+/// unsafe impl IntrusivelyCountable for Square {
+///     type RefCounter = TipToe;
+///
+///     fn ref_counter(&self) -> &Self::RefCounter {
+///         &self.counter
+///     }
+/// }
+///
+/// impl Shape for Square {
+///     fn area(&self) -> f64 {
+///         self.side * self.side
+///     }
+/// }
+///
+/// let square = Rc::pin(Square { counter: TipToe::new(), side: 2.0 });
+/// let shape: Pin<Rc<dyn Shape>> = unsize!(square => dyn Shape);
+/// assert_eq!(shape.area(), 4.0);
+/// ```
+///
+/// Other conversions don't compile:
+///
+/// ```compile_fail
+/// # use tiptoe::{unsize, IntrusivelyCountable, Rc, TipToe};
+/// # struct A { counter: TipToe }
+/// # unsafe impl IntrusivelyCountable for A {
+/// #     type RefCounter = TipToe;
+/// #     fn ref_counter(&self) -> &Self::RefCounter { &self.counter }
+/// # }
+/// # struct B { counter: TipToe }
+/// # unsafe impl IntrusivelyCountable for B {
+/// #     type RefCounter = TipToe;
+/// #     fn ref_counter(&self) -> &Self::RefCounter { &self.counter }
+/// # }
+/// let b: Rc<B> = unsize!(Rc::new(A { counter: TipToe::new() }) => B);
+/// ```
+#[macro_export]
+macro_rules! unsize {
+	($pointer:expr => $target:ty) => {
+		match $pointer {
+			pointer => unsafe {
+				$crate::__private::Unsize::<$target>::unsize(pointer, |pointer| -> *mut $target {
+					pointer
+				})
+			},
+		}
+	};
+}
+
+#[doc(hidden)]
+pub mod __private {
+	//! Implementation details of [`IntrusivelyCountable`](macro@crate::IntrusivelyCountable) and [`unsize!`](`crate::unsize`). Not public API.
+
+	#[cfg(feature = "sync")]
+	use crate::Arc;
+	use crate::{IntrusivelyCountable, Rc};
+	#[cfg(feature = "sync")]
+	use allocator_api2::alloc::Allocator;
+	use core::pin::Pin;
+
+	#[cfg(feature = "derive")]
+	use crate::{RefCounter, RefCounterFor};
+
+	#[cfg(feature = "derive")]
+	pub fn assert_ref_counter<T: ?Sized + RefCounter>() {}
+
+	#[cfg(feature = "derive")]
+	pub fn assert_ref_counter_for<C: ?Sized + RefCounterFor<T>, T: ?Sized>() {}
+
+	#[cfg(feature = "derive")]
+	pub trait IntrusivelyCountableMustNotBeUnpin<A> {
+		fn assert() {}
+	}
+	#[cfg(feature = "derive")]
+	impl<T: ?Sized> IntrusivelyCountableMustNotBeUnpin<()> for T {}
+	#[cfg(feature = "derive")]
+	impl<T: ?Sized + Unpin> IntrusivelyCountableMustNotBeUnpin<u8> for T {}
+
+	/// Dispatches [`unsize!`](`crate::unsize`) to the matching `unsize` or `unsize_pinned` function.
+	pub trait Unsize<U: ?Sized> {
+		type Pointee: ?Sized;
+		type Output;
+
+		/// # Safety
+		///
+		/// See [`Rc::unsize`].
+		unsafe fn unsize(self, unsize: impl FnOnce(*mut Self::Pointee) -> *mut U) -> Self::Output;
+	}
+
+	impl<T: ?Sized + IntrusivelyCountable, U> Unsize<U> for Rc<T>
+	where
+		U: ?Sized + IntrusivelyCountable<RefCounter = T::RefCounter>,
+	{
+		type Pointee = T;
+		type Output = Rc<U>;
+
+		unsafe fn unsize(self, unsize: impl FnOnce(*mut T) -> *mut U) -> Rc<U> {
+			Rc::unsize(self, unsize)
+		}
+	}
+
+	impl<T: ?Sized + IntrusivelyCountable, U> Unsize<U> for Pin<Rc<T>>
+	where
+		U: ?Sized + IntrusivelyCountable<RefCounter = T::RefCounter>,
+	{
+		type Pointee = T;
+		type Output = Pin<Rc<U>>;
+
+		unsafe fn unsize(self, unsize: impl FnOnce(*mut T) -> *mut U) -> Pin<Rc<U>> {
+			Rc::unsize_pinned(self, unsize)
+		}
+	}
+
+	#[cfg(feature = "sync")]
+	impl<T: ?Sized + IntrusivelyCountable, U, A: Allocator> Unsize<U> for Arc<T, A>
+	where
+		U: ?Sized + IntrusivelyCountable<RefCounter = T::RefCounter>,
+	{
+		type Pointee = T;
+		type Output = Arc<U, A>;
+
+		unsafe fn unsize(self, unsize: impl FnOnce(*mut T) -> *mut U) -> Arc<U, A> {
+			Arc::unsize(self, unsize)
+		}
+	}
+
+	#[cfg(feature = "sync")]
+	impl<T: ?Sized + IntrusivelyCountable, U, A: Allocator> Unsize<U> for Pin<Arc<T, A>>
+	where
+		U: ?Sized + IntrusivelyCountable<RefCounter = T::RefCounter>,
+	{
+		type Pointee = T;
+		type Output = Pin<Arc<U, A>>;
+
+		unsafe fn unsize(self, unsize: impl FnOnce(*mut T) -> *mut U) -> Pin<Arc<U, A>> {
+			Arc::unsize_pinned(self, unsize)
+		}
+	}
+}
 
 /// Note: The `refcount` values [`EXCLUSIVITY_MARKER`] and up are special.
 ///
@@ -88,12 +397,9 @@ const EXCLUSIVITY_MARKER: usize = usize::MAX - (usize::MAX - isize::MAX as usize
 ///
 /// Not [`Unpin`].
 ///
-/// Always [`Send`] but [`Sync`] only if the `"sync"` feature is enabled.
+/// [`Send`] but not [`Sync`]. See [`AtomicTipToe`] for a thread-safe variant.
 #[derive(Debug, Default)]
 pub struct TipToe {
-	#[cfg(feature = "sync")]
-	refcount: AtomicUsize,
-	#[cfg(not(feature = "sync"))]
 	refcount: Cell<usize>,
 	_pinned: PhantomPinned,
 }
@@ -110,146 +416,1130 @@ impl TipToe {
 	}
 }
 
-impl Clone for TipToe {
-	fn clone(&self) -> Self {
+/// An embeddable strong-only reference counter with atomic operations.
+///
+/// Transparent to [`PartialEq`], [`Eq`], [`PartialOrd`], [`Ord`] and [`Hash`], [clone](`Clone::clone`)d to its default.
+///
+/// Not [`Unpin`].
+///
+/// [`Send`] and [`Sync`]. See [`TipToe`] for a single-threaded variant.
+#[cfg(feature = "sync")]
+#[derive(Debug, Default)]
+pub struct AtomicTipToe {
+	refcount: AtomicUsize,
+	_pinned: PhantomPinned,
+}
+
+#[cfg(feature = "sync")]
+impl AtomicTipToe {
+	/// Creates as new [`AtomicTipToe`] instance.
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+}
+
+/// An embeddable reference counter pair, counting strong and weak references.
+///
+/// Use this instead of [`TipToe`] to enable [`rc::Weak`] handles.
+///
+/// Transparent to [`PartialEq`], [`Eq`], [`PartialOrd`], [`Ord`] and [`Hash`], [clone](`Clone::clone`)d to its default.
+///
+/// Not [`Unpin`].
+///
+/// [`Send`] but not [`Sync`]. See [`AtomicTipToes`] for a thread-safe variant.
+#[derive(Debug)]
+pub struct TipToes {
+	refcount: Cell<usize>,
+	/// The number of weak handles, plus one that is shared by all strong handles.
+	weak_refcount: Cell<usize>,
+	_pinned: PhantomPinned,
+}
+
+impl TipToes {
+	/// Creates as new [`TipToes`] instance.
+	///
+	/// > Two digits, for a little more stability.
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+}
+
+impl Default for TipToes {
+	fn default() -> Self {
+		Self {
+			refcount: Cell::new(0),
+			weak_refcount: Cell::new(1),
+			_pinned: PhantomPinned,
+		}
+	}
+}
+
+/// An embeddable reference counter pair, counting strong and weak references with atomic operations.
+///
+/// Use this instead of [`AtomicTipToe`] to enable [`sync::Weak`] handles.
+///
+/// Transparent to [`PartialEq`], [`Eq`], [`PartialOrd`], [`Ord`] and [`Hash`], [clone](`Clone::clone`)d to its default.
+///
+/// Not [`Unpin`].
+///
+/// [`Send`] and [`Sync`]. See [`TipToes`] for a single-threaded variant.
+#[cfg(feature = "sync")]
+#[derive(Debug)]
+pub struct AtomicTipToes {
+	refcount: AtomicUsize,
+	/// The number of weak handles, plus one that is shared by all strong handles.
+	weak_refcount: AtomicUsize,
+	_pinned: PhantomPinned,
+}
+
+#[cfg(feature = "sync")]
+impl AtomicTipToes {
+	/// Creates as new [`AtomicTipToes`] instance.
+	#[must_use]
+	pub fn new() -> Self {
 		Self::default()
 	}
 }
 
-impl PartialEq for TipToe {
-	fn eq(&self, _: &Self) -> bool {
-		true
+#[cfg(feature = "sync")]
+impl Default for AtomicTipToes {
+	fn default() -> Self {
+		Self {
+			refcount: AtomicUsize::new(0),
+			weak_refcount: AtomicUsize::new(1),
+			_pinned: PhantomPinned,
+		}
 	}
 }
 
-impl Eq for TipToe {}
+/// An embeddable strong-only reference counter that lets one owner thread count without atomic operations.
+///
+/// This implements biased reference counting:
+/// Creating an [`Arc`] with the global allocator makes the current thread the owner of the fresh instance's reference count,
+/// so that it can clone and drop handles non-atomically, while other threads count their share atomically.
+/// Once the owner thread's share reaches zero, it is merged into the shared count
+/// and the counter behaves like an [`AtomicTipToe`] until it is [reset](`ref_counter_api::RefCounterExt::reset`).
+///
+/// Since other threads can't see the owner thread's share:
+///
+/// - Uniqueness checks (e.g. [`Arc::get_mut`] or [`Arc::try_unwrap`]) fail on other threads while the owner thread's share isn't merged.
+///   On the owner thread, they merge it first.
+/// - A handle whose drop would take the other threads' share below zero is handed to the owner thread instead,
+///   which drops it the next time it clones or drops an [`Arc`] of a [`BiasedTipToe`]-counted payload,
+///   during [`BiasedTipToe::drop_deferred`] or when exiting.
+///   If the owner thread already exited, its share is merged instead.
+///
+///   > As only [`Arc`] can do this, [`BiasedTipToe`] isn't an [`UnbiasedRefCounter`],
+///   > so custom containers can't give up references through [`RefCounterExt::decrement`](`ref_counter_api::RefCounterExt::decrement`):
+///   >
+///   > ```compile_fail
+///   > use tiptoe::{ref_counter_api::RefCounterExt, BiasedTipToe};
+///   >
+///   > let counter = BiasedTipToe::new();
+///   > counter.increment();
+///   > unsafe { counter.decrement() };
+///   > ```
+///
+/// Transparent to [`PartialEq`], [`Eq`], [`PartialOrd`], [`Ord`] and [`Hash`], [clone](`Clone::clone`)d to its default.
+///
+/// Not [`Unpin`].
+///
+/// [`Send`] and [`Sync`].
+///
+/// # Safety Notes
+///
+/// As handles may be dropped on the owner thread later than expected,
+/// [`IntrusivelyCountable`] must only be implemented with this counter for types that are `'static`.
+///
+/// [`Counted`](`counted::Counted`) and [`#[derive(IntrusivelyCountable)]`](macro@IntrusivelyCountable) check this through [`RefCounterFor`]:
+///
+/// ```compile_fail
+/// use tiptoe::{counted::Counted, Arc, BiasedTipToe};
+///
+/// let string = String::from("borrowed");
+/// let arc = Arc::new(Counted::<_, BiasedTipToe>::new(string.as_str()));
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct BiasedTipToe {
+	refcount: ref_counter_api::BiasedCount,
+	_pinned: PhantomPinned,
+}
 
-impl PartialOrd for TipToe {
-	fn partial_cmp(&self, _: &Self) -> Option<cmp::Ordering> {
-		Some(cmp::Ordering::Equal)
+#[cfg(feature = "std")]
+impl BiasedTipToe {
+	/// Creates as new [`BiasedTipToe`] instance.
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
 	}
+
+	/// Drops the [`Arc`]s that other threads handed to the current thread, as they couldn't decrement their reference count themselves.
+	///
+	/// This also happens whenever the current thread clones or drops an [`Arc`] of a [`BiasedTipToe`]-counted payload.
+	/// Threads that create long-lived [`Arc`]s of [`BiasedTipToe`]-counted payloads which are then dropped elsewhere,
+	/// but that don't otherwise keep handling such [`Arc`]s, should call this periodically,
+	/// as these payloads are otherwise only freed when the thread exits.
+	pub fn drop_deferred() {
+		biased::Owner::drop_deferred()
+	}
+}
+
+/// An embeddable strong-only reference counter for instances that are cloned and dropped on many threads at once,
+/// in the spirit of Linux's `percpu_ref`.
+///
+/// The counter starts out *collapsed*, in which case it behaves like an [`AtomicTipToe`].
+/// [`ShardedTipToe::shard`] switches it into *sharded* mode, in which each thread counts on one of several slots on separate cache lines,
+/// so that handles don't contend over a single count.
+///
+/// A sharded counter holds a base reference of its own, so decrements that its slots can absorb never have to check for zero.
+/// Checks for exclusivity or for the last reference (e.g. [`Arc::get_mut`], [`Arc::try_unwrap`] or [`Rc::try_unwrap`])
+/// and [`ShardedTipToe::collapse`] merge the slots and release the base reference again.
+///
+/// A decrement that would take its thread's slot below zero, e.g. when dropping a handle that was cloned on another thread,
+/// collapses the counter too, so that dropping the last handle always drops the payload.
+/// Shard the counter again afterwards where that's useful.
+///
+/// Transparent to [`PartialEq`], [`Eq`], [`PartialOrd`], [`Ord`] and [`Hash`], [clone](`Clone::clone`)d to its default.
+///
+/// Not [`Unpin`].
+///
+/// [`Send`] and [`Sync`].
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct ShardedTipToe {
+	refcount: ref_counter_api::ShardedCount,
+	_pinned: PhantomPinned,
 }
 
-impl Ord for TipToe {
-	fn cmp(&self, _: &Self) -> cmp::Ordering {
-		cmp::Ordering::Equal
+#[cfg(feature = "std")]
+impl ShardedTipToe {
+	/// Creates as new [`ShardedTipToe`] instance.
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Switches the counter into sharded mode, unless it is zero (i.e. not in use by reference-counting pointers) or exclusively borrowed.
+	///
+	/// Returns whether this call switched the counter, i.e. `false` also if it was sharded already.
+	pub fn shard(&self) -> bool {
+		self.refcount.shard()
+	}
+
+	/// Switches the counter into collapsed mode, if it is sharded, so that it counts on a single atomic again.
+	pub fn collapse(&self) {
+		self.refcount.collapse()
 	}
 }
 
-impl Hash for TipToe {
-	fn hash<H: core::hash::Hasher>(&self, _: &mut H) {}
+macro_rules! transparent_counter_impls {
+	($($(#[$attribute:meta])* $name:ident),*$(,)?) => {$(
+		$(#[$attribute])*
+		impl Clone for $name {
+			fn clone(&self) -> Self {
+				Self::default()
+			}
+		}
+
+		$(#[$attribute])*
+		impl PartialEq for $name {
+			fn eq(&self, _: &Self) -> bool {
+				true
+			}
+		}
+
+		$(#[$attribute])*
+		impl Eq for $name {}
+
+		$(#[$attribute])*
+		#[allow(clippy::non_canonical_partial_ord_impl)]
+		impl PartialOrd for $name {
+			fn partial_cmp(&self, _: &Self) -> Option<cmp::Ordering> {
+				Some(cmp::Ordering::Equal)
+			}
+		}
+
+		$(#[$attribute])*
+		impl Ord for $name {
+			fn cmp(&self, _: &Self) -> cmp::Ordering {
+				cmp::Ordering::Equal
+			}
+		}
+
+		$(#[$attribute])*
+		impl Hash for $name {
+			fn hash<H: core::hash::Hasher>(&self, _: &mut H) {}
+		}
+	)*};
 }
+transparent_counter_impls!(
+	TipToe,
+	#[cfg(feature = "sync")]
+	AtomicTipToe,
+	TipToes,
+	#[cfg(feature = "sync")]
+	AtomicTipToes,
+	#[cfg(feature = "std")]
+	BiasedTipToe,
+	#[cfg(feature = "std")]
+	ShardedTipToe,
+);
 
 pub mod ref_counter_api {
 	//! Low-level [`RefCounter`] API for custom intrusive reference-counting containers.
 
-	use crate::{RefCounter, EXCLUSIVITY_MARKER};
+	#[cfg(feature = "std")]
+	use crate::biased::Deferral;
+	use crate::{
+		handle_exclusivity_violation, ExclusivityViolation, RefCounter, UnbiasedRefCounter,
+		WeakRefCounter, EXCLUSIVITY_MARKER,
+	};
 	use abort::abort;
+	use core::{hint::spin_loop, ptr::NonNull, sync::atomic::Ordering};
+	use private::Count;
 
-	#[cfg(any(feature = "sync", doc))]
-	use core::sync::atomic::Ordering;
+	/// Weak reference count value that marks it as locked by [`RefCounterExt::try_decrement_unique`].
+	const WEAK_LOCKED: usize = usize::MAX;
 
 	mod private {
-		#[cfg(not(feature = "sync"))]
-		use core::cell::Cell;
 		#[cfg(feature = "sync")]
 		use core::sync::atomic::AtomicUsize;
+		use core::{cell::Cell, sync::atomic::Ordering};
+		#[cfg(feature = "std")]
+		use core::{
+			cmp,
+			fmt::{self, Debug, Formatter},
+			hint,
+			ptr::{self, NonNull},
+			sync::atomic::AtomicPtr,
+		};
 
-		use crate::TipToe;
+		#[cfg(feature = "std")]
+		use crate::{
+			biased::{Deferral, Owner},
+			sharded, BiasedTipToe, ShardedTipToe,
+		};
+		#[cfg(feature = "sync")]
+		use crate::{AtomicTipToe, AtomicTipToes};
+		use crate::{TipToe, TipToes, EXCLUSIVITY_MARKER};
+		#[cfg(feature = "std")]
+		use abort::abort;
 
-		pub trait Sealed: 'static {
-			#[cfg(feature = "sync")]
-			fn refcount(&self) -> &AtomicUsize;
-			#[cfg(not(feature = "sync"))]
-			fn refcount(&self) -> &Cell<usize>;
+		/// The storage of a single count, with an [atomic](`core::sync::atomic`)-like interface.
+		///
+		/// Non-atomic implementations ignore the [`Ordering`] parameters.
+		pub trait Count: 'static {
+			/// Incrementing the count at or past this value is handled as overflow or as exclusivity violation.
+			const INCREMENT_LIMIT: usize;
+
+			/// Whether [`Count::fetch_sub_or_defer`] may hand decrements to another thread.
+			#[cfg(feature = "std")]
+			const DEFERS: bool = false;
+
+			fn load(&self, order: Ordering) -> usize;
+			fn store(&self, value: usize, order: Ordering);
+			fn fetch_add(&self, value: usize, order: Ordering) -> usize;
+			fn fetch_sub(&self, value: usize, order: Ordering) -> usize;
+			fn compare_exchange(
+				&self,
+				current: usize,
+				new: usize,
+				success: Ordering,
+				failure: Ordering,
+			) -> Result<usize, usize>;
+			fn compare_exchange_weak(
+				&self,
+				current: usize,
+				new: usize,
+				success: Ordering,
+				failure: Ordering,
+			) -> Result<usize, usize>;
 
-			fn refcount_ptr(&self) -> *mut usize {
-				#[cfg(feature = "sync")]
-				return self.refcount() as *const AtomicUsize as *mut usize;
-				#[cfg(not(feature = "sync"))]
-				return self.refcount().as_ptr();
+			/// Like [`Count::fetch_sub`], but hands the decrement to another thread instead where the count requires that.
+			#[cfg(feature = "std")]
+			fn fetch_sub_or_defer(&self, value: usize, order: Ordering) -> Result<usize, Deferral> {
+				Ok(self.fetch_sub(value, order))
 			}
+
+			/// Lets the current thread count the only reference to a fresh [`Arc`](`crate::Arc`) non-atomically,
+			/// if supported.
+			///
+			/// # Safety
+			///
+			/// The count must belong to an instance that was just placed into an [`Arc`](`crate::Arc`) with the global allocator,
+			/// whose only handle wasn't shared yet.
+			unsafe fn bias(&self) {}
 		}
-		impl Sealed for TipToe {
-			#[allow(clippy::inline_always)]
-			#[cfg(feature = "sync")]
-			#[inline(always)]
-			fn refcount(&self) -> &AtomicUsize {
-				&self.refcount
+
+		impl Count for Cell<usize> {
+			// See `alloc::rc::RcInnerPtr::inc_strong`:
+			// <https://github.com/rust-lang/rust/blob/81117ff930fbf3792b4f9504e3c6bccc87b10823/library/alloc/src/rc.rs#L2442-L2453>
+			const INCREMENT_LIMIT: usize = EXCLUSIVITY_MARKER - 1;
+
+			#[inline]
+			fn load(&self, _: Ordering) -> usize {
+				self.get()
+			}
+
+			#[inline]
+			fn store(&self, value: usize, _: Ordering) {
+				self.set(value)
 			}
-			#[allow(clippy::inline_always)]
-			#[cfg(not(feature = "sync"))]
-			#[inline(always)]
-			fn refcount(&self) -> &Cell<usize> {
-				&self.refcount
+
+			#[inline]
+			fn fetch_add(&self, value: usize, _: Ordering) -> usize {
+				self.replace(self.get() + value)
+			}
+
+			#[inline]
+			fn fetch_sub(&self, value: usize, _: Ordering) -> usize {
+				self.replace(self.get() - value)
+			}
+
+			#[inline]
+			fn compare_exchange(
+				&self,
+				current: usize,
+				new: usize,
+				_: Ordering,
+				_: Ordering,
+			) -> Result<usize, usize> {
+				let old = self.get();
+				if old == current {
+					self.set(new);
+					Ok(old)
+				} else {
+					Err(old)
+				}
+			}
+
+			#[inline]
+			fn compare_exchange_weak(
+				&self,
+				current: usize,
+				new: usize,
+				success: Ordering,
+				failure: Ordering,
+			) -> Result<usize, usize> {
+				Count::compare_exchange(self, current, new, success, failure)
+			}
+		}
+
+		#[cfg(feature = "sync")]
+		impl Count for AtomicUsize {
+			// See `alloc::Sync::Arc`'s clone implementation for why it's necessary to guard against immense reference counts:
+			// <https://github.com/rust-lang/rust/blob/81117ff930fbf3792b4f9504e3c6bccc87b10823/library/alloc/src/sync.rs#L1327-L1338>
+			//
+			// In short:
+			//
+			// An overflow could cause a use-after free. There likely aren't about `isize::MAX` threads that can race here, though, and `isize::MAX` is a decently high limit.
+			const INCREMENT_LIMIT: usize = isize::MAX as usize;
+
+			#[inline]
+			fn load(&self, order: Ordering) -> usize {
+				self.load(order)
+			}
+
+			#[inline]
+			fn store(&self, value: usize, order: Ordering) {
+				self.store(value, order)
+			}
+
+			#[inline]
+			fn fetch_add(&self, value: usize, order: Ordering) -> usize {
+				self.fetch_add(value, order)
+			}
+
+			#[inline]
+			fn fetch_sub(&self, value: usize, order: Ordering) -> usize {
+				self.fetch_sub(value, order)
+			}
+
+			#[inline]
+			fn compare_exchange(
+				&self,
+				current: usize,
+				new: usize,
+				success: Ordering,
+				failure: Ordering,
+			) -> Result<usize, usize> {
+				self.compare_exchange(current, new, success, failure)
+			}
+
+			#[inline]
+			fn compare_exchange_weak(
+				&self,
+				current: usize,
+				new: usize,
+				success: Ordering,
+				failure: Ordering,
+			) -> Result<usize, usize> {
+				self.compare_exchange_weak(current, new, success, failure)
+			}
+		}
+
+		/// The owner thread's share is tracked as this offset while it's not merged into [`BiasedCount::shared`].
+		#[cfg(feature = "std")]
+		const BIASED: usize = isize::MAX as usize + 1;
+
+		/// Each share of a [`BiasedCount`] is kept below this, so that their sum can't reach [`EXCLUSIVITY_MARKER`].
+		#[cfg(feature = "std")]
+		const BIASED_LIMIT: usize = (EXCLUSIVITY_MARKER - BIASED) / 2;
+
+		/// A reference count that is split between an owner thread and all other threads.
+		///
+		/// See [`BiasedTipToe`] for the overall behaviour.
+		#[cfg(feature = "std")]
+		#[derive(Default)]
+		pub struct BiasedCount {
+			/// The thread that may access [`BiasedCount::biased`], if any.
+			owner: AtomicPtr<Owner>,
+			/// The owner thread's share, or `0` once it was merged into [`BiasedCount::shared`].
+			biased: Cell<usize>,
+			/// [`BIASED`] plus the other threads' share while the owner thread's share isn't merged,
+			/// otherwise the whole count.
+			shared: AtomicUsize,
+		}
+
+		#[cfg(feature = "std")]
+		impl Debug for BiasedCount {
+			fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+				f.debug_struct("BiasedCount")
+					.field("shared", &self.shared)
+					.finish_non_exhaustive()
 			}
 		}
+
+		/// [`BiasedCount::biased`] is only accessed by the owner thread,
+		/// by other threads while they hold its lock after it exited,
+		/// or while the count is otherwise known to be unshared.
+		#[cfg(feature = "std")]
+		unsafe impl Sync for BiasedCount {}
+
+		#[cfg(feature = "std")]
+		impl BiasedCount {
+			/// The other threads' share, iff the owner thread's share isn't merged.
+			fn unmerged(shared: usize) -> Option<usize> {
+				(BIASED..EXCLUSIVITY_MARKER)
+					.contains(&shared)
+					.then(|| shared - BIASED)
+			}
+
+			/// The owner thread's share, iff called on the owner thread and that share isn't merged.
+			fn owned(&self) -> Option<usize> {
+				let owner = self.owner.load(Ordering::Relaxed);
+				match Owner::current() {
+					Some(current) if ptr::eq(owner, current) => match self.biased.get() {
+						0 => None,
+						biased => Some(biased),
+					},
+					_ => None,
+				}
+			}
+
+			/// Merges what remains of the owner thread's share and returns the other threads' share before that.
+			///
+			/// Must only be called where [`BiasedCount::biased`] may be accessed and is non-zero.
+			fn merge(&self, remaining: usize) -> usize {
+				self.biased.set(0);
+				self.shared
+					.fetch_add(remaining.wrapping_sub(BIASED), Ordering::AcqRel)
+					.wrapping_sub(BIASED)
+			}
+
+			/// Merges the owner thread's share iff the owner thread exited.
+			///
+			/// Must only be called while the owner thread's share isn't merged.
+			fn merge_abandoned(&self) -> Result<(), Deferral> {
+				// Set before the share became unmerged, in a way that is ordered before any handle was shared.
+				let owner = unsafe { &*self.owner.load(Ordering::Relaxed) };
+				let state = owner.lock();
+				if !state.exited() {
+					return Err(Deferral::new(owner));
+				}
+
+				// Another thread may have merged it already.
+				match self.biased.get() {
+					0 => (),
+					biased => drop(self.merge(biased)),
+				}
+				Ok(())
+			}
+		}
+
+		#[cfg(feature = "std")]
+		impl Count for BiasedCount {
+			const INCREMENT_LIMIT: usize = isize::MAX as usize;
+			const DEFERS: bool = true;
+
+			fn load(&self, order: Ordering) -> usize {
+				let shared = self.shared.load(order);
+				match Self::unmerged(shared) {
+					// The owner thread's share is at least `1` where it can't be observed.
+					Some(shared) => shared + self.owned().unwrap_or(1),
+					None => shared,
+				}
+			}
+
+			fn store(&self, value: usize, order: Ordering) {
+				// Only used while resetting the counter, with exclusive access.
+				self.owner.store(ptr::null_mut(), Ordering::Relaxed);
+				self.biased.set(0);
+				self.shared.store(value, order)
+			}
+
+			fn fetch_add(&self, value: usize, order: Ordering) -> usize {
+				if let Some(biased) = self.owned() {
+					if biased + value > BIASED_LIMIT {
+						abort()
+					}
+					self.biased.set(biased + value);
+					return biased;
+				}
+
+				let shared = self.shared.fetch_add(value, order);
+				match Self::unmerged(shared) {
+					Some(shared) if shared + value > BIASED_LIMIT => abort(),
+					Some(shared) => shared + 1,
+					None => shared,
+				}
+			}
+
+			fn fetch_sub(&self, value: usize, order: Ordering) -> usize {
+				// Only reached for counts that aren't biased (see `UnbiasedRefCounter`), where this can't fail.
+				// Leaking the reference is the safe fallback anyway.
+				self.fetch_sub_or_defer(value, order).unwrap_or(value + 1)
+			}
+
+			fn fetch_sub_or_defer(&self, value: usize, order: Ordering) -> Result<usize, Deferral> {
+				if let Some(biased) = self.owned() {
+					return Ok(match biased.cmp(&value) {
+						cmp::Ordering::Greater => {
+							self.biased.set(biased - value);
+							biased
+						}
+						// The owner thread's share reaches zero.
+						cmp::Ordering::Equal => self.merge(0) + biased,
+						// The remainder was counted by other threads.
+						cmp::Ordering::Less => {
+							self.merge(0);
+							self.shared.fetch_sub(value - biased, order) + biased
+						}
+					});
+				}
+
+				let mut shared = self.shared.load(Ordering::Relaxed);
+				loop {
+					match Self::unmerged(shared) {
+						None => return Ok(self.shared.fetch_sub(value, order)),
+						Some(others) if others >= value => {
+							match self.shared.compare_exchange_weak(
+								shared,
+								shared - value,
+								order,
+								Ordering::Relaxed,
+							) {
+								Ok(_) => return Ok(others + 1),
+								Err(current) => shared = current,
+							}
+						}
+						Some(_) => {
+							self.merge_abandoned()?;
+							shared = self.shared.load(Ordering::Relaxed);
+						}
+					}
+				}
+			}
+
+			fn compare_exchange(
+				&self,
+				current: usize,
+				new: usize,
+				success: Ordering,
+				failure: Ordering,
+			) -> Result<usize, usize> {
+				// Increments can't affect an unmerged count's value being zero or an exclusivity marker.
+				let increment = current != 0 && current.checked_add(1) == Some(new);
+				if let Some(biased) = self.owned() {
+					if increment {
+						self.fetch_add(1, success);
+						return Ok(current);
+					}
+					self.merge(biased);
+				} else if let Some(others) = Self::unmerged(self.shared.load(failure)) {
+					if increment {
+						self.fetch_add(1, success);
+						return Ok(current);
+					} else if self.merge_abandoned().is_err() {
+						// Reported as shared, as the owner thread's share can't be observed.
+						return Err(others + 2);
+					}
+				}
+				self.shared.compare_exchange(current, new, success, failure)
+			}
+
+			fn compare_exchange_weak(
+				&self,
+				current: usize,
+				new: usize,
+				success: Ordering,
+				failure: Ordering,
+			) -> Result<usize, usize> {
+				Count::compare_exchange(self, current, new, success, failure)
+			}
+
+			unsafe fn bias(&self) {
+				if self.shared.load(Ordering::Relaxed) == 1 {
+					if let Some(owner) = Owner::register() {
+						self.owner
+							.store(NonNull::from(owner).as_ptr(), Ordering::Relaxed);
+						self.biased.set(1);
+						self.shared.store(BIASED, Ordering::Relaxed);
+					}
+				}
+			}
+		}
+
+		/// The number of slots of a [`ShardedCount`].
+		#[cfg(feature = "std")]
+		const SLOTS: usize = 16;
+
+		/// Marks a slot of a [`ShardedCount`] as merged into [`ShardedCount::central`].
+		#[cfg(feature = "std")]
+		const SEALED: usize = isize::MAX as usize + 1;
+
+		/// Set in [`ShardedCount::central`] while the count is sharded.
+		#[cfg(feature = "std")]
+		const SHARDED: usize = isize::MAX as usize + 1;
+
+		/// Set in [`ShardedCount::central`] while a thread switches the count between modes.
+		#[cfg(feature = "std")]
+		const SWITCHING: usize = SHARDED >> 2;
+
+		/// Added to the central share of a sharded count as a margin, so that it can't borrow from the flags.
+		#[cfg(feature = "std")]
+		const SHARDED_OFFSET: usize = SHARDED >> 3;
+
+		/// The central share of a sharded count and the slots' sum are each kept below this,
+		/// so that [`ShardedCount::central`] stays between [`SHARDED`] and [`EXCLUSIVITY_MARKER`].
+		#[cfg(feature = "std")]
+		const SHARDED_LIMIT: usize = SHARDED >> 4;
+
+		/// Each slot's value is kept below this.
+		#[cfg(feature = "std")]
+		const SLOT_LIMIT: usize = SHARDED_LIMIT / SLOTS;
+
+		/// A reference count that is spread over several slots while sharded.
+		///
+		/// See [`ShardedTipToe`] for the overall behaviour.
+		#[cfg(feature = "std")]
+		pub struct ShardedCount {
+			/// The whole count while collapsed, otherwise [`SHARDED`], [`SHARDED_OFFSET`] and the central share including the base reference.
+			central: AtomicUsize,
+			/// The slots' shares while sharded, otherwise [`SEALED`].
+			///
+			/// A slot never goes below zero, so that the base reference and the handles counted centrally
+			/// keep the sum above zero until a decrement collapses the count.
+			slots: [Slot; SLOTS],
+		}
+
+		/// Keeps slots on separate cache lines.
+		#[cfg(feature = "std")]
+		#[repr(align(128))]
+		struct Slot(AtomicUsize);
+
+		#[cfg(feature = "std")]
+		impl Default for ShardedCount {
+			fn default() -> Self {
+				#[allow(clippy::declare_interior_mutable_const)]
+				const SLOT: Slot = Slot(AtomicUsize::new(SEALED));
+				Self {
+					central: AtomicUsize::new(0),
+					slots: [SLOT; SLOTS],
+				}
+			}
+		}
+
+		#[cfg(feature = "std")]
+		impl Debug for ShardedCount {
+			fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+				f.debug_struct("ShardedCount")
+					.field("central", &self.central)
+					.finish_non_exhaustive()
+			}
+		}
+
+		#[cfg(feature = "std")]
+		impl ShardedCount {
+			/// The central share plus [`SHARDED_OFFSET`], iff `central` is a sharded count.
+			fn share(central: usize) -> Option<usize> {
+				(SHARDED..EXCLUSIVITY_MARKER)
+					.contains(&central)
+					.then_some(central & (SWITCHING - 1))
+			}
+
+			/// Replaces the value of a sharded count with a stand-in that is known to be accurate enough.
+			fn nominal(central: usize) -> usize {
+				match Self::share(central) {
+					// The exact count isn't known without collapsing, but the base reference keeps it above `1`.
+					Some(_) => 2,
+					None => central,
+				}
+			}
+
+			/// Adds or subtracts `value` on the current thread's slot, iff that slot isn't sealed and stays within `0..SLOT_LIMIT`.
+			///
+			/// Returns whether `value` was counted.
+			fn count_on_slot(&self, value: usize, subtract: bool, order: Ordering) -> bool {
+				let slot = match sharded::index(SLOTS) {
+					Some(index) => &self.slots[index].0,
+					None => return false,
+				};
+
+				let mut current = slot.load(Ordering::Relaxed);
+				loop {
+					if current == SEALED {
+						return false;
+					}
+					let new = match if subtract {
+						current.checked_sub(value)
+					} else {
+						current.checked_add(value)
+					} {
+						Some(new) if new < SLOT_LIMIT => new,
+						_ => return false,
+					};
+					match slot.compare_exchange_weak(current, new, order, Ordering::Relaxed) {
+						Ok(_) => return true,
+						Err(changed) => current = changed,
+					}
+				}
+			}
+
+			/// Claims [`SWITCHING`] while `central` satisfies `predicate`, waiting for other switches to finish.
+			///
+			/// Returns whether [`SWITCHING`] was claimed.
+			fn claim(&self, predicate: impl Fn(usize) -> bool) -> bool {
+				let mut central = self.central.load(Ordering::Relaxed);
+				loop {
+					if !predicate(central) {
+						return false;
+					}
+					if central & SWITCHING != 0 && Self::share(central).is_some() {
+						hint::spin_loop();
+						central = self.central.load(Ordering::Relaxed);
+						continue;
+					}
+
+					let claimed = match Self::share(central) {
+						Some(_) => central | SWITCHING,
+						// The base reference is taken along with the switch.
+						None => SHARDED + SWITCHING + SHARDED_OFFSET + central + 1,
+					};
+					match self.central.compare_exchange_weak(
+						central,
+						claimed,
+						Ordering::Acquire,
+						Ordering::Relaxed,
+					) {
+						Ok(_) => return true,
+						Err(changed) => central = changed,
+					}
+				}
+			}
+
+			pub(crate) fn shard(&self) -> bool {
+				// Zero means the instance isn't in use, so it mustn't gain a (base) reference.
+				if !self.claim(|central| (1..SHARDED_LIMIT).contains(&central)) {
+					return false;
+				}
+
+				for slot in &self.slots {
+					slot.0.store(0, Ordering::Relaxed);
+				}
+				self.central.fetch_sub(SWITCHING, Ordering::Release);
+				true
+			}
+
+			pub(crate) fn collapse(&self) {
+				if !self.claim(|central| Self::share(central).is_some()) {
+					return;
+				}
+
+				for slot in &self.slots {
+					let share = slot.0.swap(SEALED, Ordering::AcqRel);
+					self.central.fetch_add(share, Ordering::AcqRel);
+				}
+
+				// Since the caller holds a reference, this can't reach zero.
+				self.central
+					.fetch_sub(SHARDED + SWITCHING + SHARDED_OFFSET + 1, Ordering::AcqRel);
+			}
+		}
+
+		#[cfg(feature = "std")]
+		impl Count for ShardedCount {
+			const INCREMENT_LIMIT: usize = isize::MAX as usize;
+
+			fn load(&self, order: Ordering) -> usize {
+				Self::nominal(self.central.load(order))
+			}
+
+			fn store(&self, value: usize, order: Ordering) {
+				// Only used while resetting the counter, with exclusive access.
+				for slot in &self.slots {
+					slot.0.store(SEALED, Ordering::Relaxed);
+				}
+				self.central.store(value, order)
+			}
+
+			fn fetch_add(&self, value: usize, order: Ordering) -> usize {
+				// Slots are only unsealed while the count is sharded,
+				// in which case the base reference keeps it above `value`.
+				if self.count_on_slot(value, false, order) {
+					return value + 1;
+				}
+
+				let central = self.central.fetch_add(value, order);
+				match Self::share(central) {
+					Some(share) if share + value > SHARDED_OFFSET + SHARDED_LIMIT => abort(),
+					Some(_) => value + 1,
+					None => central,
+				}
+			}
+
+			fn fetch_sub(&self, value: usize, order: Ordering) -> usize {
+				if self.count_on_slot(value, true, order) {
+					return value + 1;
+				}
+
+				// These may be the last references, which only the exact count can tell.
+				self.collapse();
+				let central = self.central.fetch_sub(value, order);
+				match Self::share(central) {
+					Some(_) => value + 1,
+					None => central,
+				}
+			}
+
+			fn compare_exchange(
+				&self,
+				current: usize,
+				new: usize,
+				success: Ordering,
+				failure: Ordering,
+			) -> Result<usize, usize> {
+				// Increments can't affect a sharded count's value being zero or an exclusivity marker.
+				let increment = current != 0 && current.checked_add(1) == Some(new);
+				if increment && self.count_on_slot(1, false, success) {
+					return Ok(current);
+				}
+
+				let mut central = self.central.load(failure);
+				while let Some(share) = Self::share(central) {
+					if !increment {
+						self.collapse();
+						break;
+					} else if share >= SHARDED_OFFSET + SHARDED_LIMIT {
+						abort()
+					}
+					match self
+						.central
+						.compare_exchange_weak(central, central + 1, success, failure)
+					{
+						Ok(_) => return Ok(current),
+						Err(changed) => central = changed,
+					}
+				}
+				// The count may have been sharded again in the meantime.
+				self.central
+					.compare_exchange(current, new, success, failure)
+					.map_err(Self::nominal)
+			}
+
+			fn compare_exchange_weak(
+				&self,
+				current: usize,
+				new: usize,
+				success: Ordering,
+				failure: Ordering,
+			) -> Result<usize, usize> {
+				Count::compare_exchange(self, current, new, success, failure)
+			}
+		}
+
+		pub trait Sealed: 'static {
+			type Count: Count;
+
+			fn refcount(&self) -> &Self::Count;
+
+			/// [`None`] iff weak references aren't supported.
+			fn weak_refcount(&self) -> Option<&Self::Count> {
+				None
+			}
+		}
+
+		macro_rules! sealed_impls {
+			($($(#[$attribute:meta])* $name:ident: $count:ty),*$(,)?) => {$(
+				$(#[$attribute])*
+				impl Sealed for $name {
+					type Count = $count;
+
+					#[allow(clippy::inline_always)]
+					#[inline(always)]
+					fn refcount(&self) -> &Self::Count {
+						&self.refcount
+					}
+				}
+			)*};
+		}
+		sealed_impls!(
+			TipToe: Cell<usize>,
+			#[cfg(feature = "sync")]
+			AtomicTipToe: AtomicUsize,
+			#[cfg(feature = "std")]
+			BiasedTipToe: BiasedCount,
+			#[cfg(feature = "std")]
+			ShardedTipToe: ShardedCount,
+		);
+
+		macro_rules! weak_sealed_impls {
+			($($(#[$attribute:meta])* $name:ident: $count:ty),*$(,)?) => {$(
+				$(#[$attribute])*
+				impl Sealed for $name {
+					type Count = $count;
+
+					#[allow(clippy::inline_always)]
+					#[inline(always)]
+					fn refcount(&self) -> &Self::Count {
+						&self.refcount
+					}
+
+					#[allow(clippy::inline_always)]
+					#[inline(always)]
+					fn weak_refcount(&self) -> Option<&Self::Count> {
+						Some(&self.weak_refcount)
+					}
+				}
+			)*};
+		}
+		weak_sealed_impls!(
+			TipToes: Cell<usize>,
+			#[cfg(feature = "sync")]
+			AtomicTipToes: AtomicUsize,
+		);
 	}
 	pub(super) use private::Sealed;
+	#[cfg(feature = "std")]
+	pub(super) use private::{BiasedCount, ShardedCount};
 
 	/// Common reference-count manipulation methods.
+	///
+	/// Whether these use atomic operations depends on the implementing counter type.
+	/// The [`Ordering`]s mentioned below only apply to atomic counters.
 	pub trait RefCounterExt: RefCounter {
 		/// Increments the reference count with [`Ordering::Relaxed`].
 		///
-		/// # Safety Notes
+		/// # Safety Notes
+		///
+		/// This is a safe operation, but incrementing the reference count too far will abort the current process rather than risk an overflow.
+		///
+		/// The (soft!) limit of atomic counters mirrors that of the standard library as of 2021-10-13.  
+		/// The (soft!) limit of non-atomic counters will be somewhat higher.
+		///
+		/// # Panics
+		///
+		/// Iff called during exclusivity, by default.
+		///
+		/// This behaviour can be changed using [`set_exclusivity_violation_handler`](`crate::set_exclusivity_violation_handler`).
+		///
+		/// # Aborts
+		///
+		/// This function may abort in cases where the reference count becomes VERY high (for the given target platform),
+		/// or during a race condition when dropping an [`Exclusivity`] erroneously while this function executes.
+		#[inline]
+		#[allow(clippy::doc_comment_double_space_linebreaks)]
+		fn increment(&self) {
+			self.increment_n(1)
+		}
+
+		/// Increments the reference count by `n` at once with [`Ordering::Relaxed`], e.g. to make `n` handles.
+		///
+		/// # Safety Notes
+		///
+		/// See [`RefCounterExt::increment`]. Additionally, this aborts iff `n` exceeds `isize::MAX / 2`.
+		///
+		/// # Panics
+		///
+		/// Iff called during exclusivity with nonzero `n`, by default.
+		///
+		/// This behaviour can be changed using [`set_exclusivity_violation_handler`](`crate::set_exclusivity_violation_handler`).
+		///
+		/// # Aborts
+		///
+		/// See [`RefCounterExt::increment`].
+		#[inline]
+		fn increment_n(&self, n: usize) {
+			// Keeps the addition below from wrapping around for each counter type.
+			if n > isize::MAX as usize / 2 {
+				abort()
+			} else if n == 0 {
+				return;
+			}
+
+			let refcount = self.refcount();
+			let old_count = refcount.fetch_add(n, Ordering::Relaxed);
+			if old_count > <Self::Count as Count>::INCREMENT_LIMIT - n {
+				if old_count >= EXCLUSIVITY_MARKER {
+					// This is actually a handle clone during an exclusive borrow.
+					// We'll revert the refcount and panic instead of aborting.
+					// (TODO: Examine performance implications of having this branch here.)
+					if refcount.fetch_sub(n, Ordering::Relaxed) >= EXCLUSIVITY_MARKER + n {
+						handle_exclusivity_violation()
+					} else {
+						// We likely got outraced by an `Exclusivity` drop.
+						// That's quite badly erroneous and could cause data corruption elsewhere
+						// due to the now most likely invalid reference count.
+						abort()
+					}
+				} else {
+					abort()
+				}
+			}
+		}
+
+		/// Increments the reference count with [`Ordering::Relaxed`], unless called during exclusivity.
+		///
+		/// Unlike [`RefCounterExt::increment`], this never observably changes the reference count during exclusivity,
+		/// so it also can't abort due to a race condition with an [`Exclusivity`] drop.
 		///
-		/// This is a safe operation, but incrementing the reference count too far will abort the current process rather than risk an overflow.
+		/// # Errors
+		///
+		/// Iff called during exclusivity.
 		///
-		/// The (soft!) limit with the `"sync"` feature mirrors that of the standard library as of 2021-10-13.  
-		/// The (soft!) limit without that feature will be somewhat higher.
+		/// # Aborts
 		///
-		/// # Panics
+		/// Iff the reference count becomes VERY high (for the given target platform).
+		fn try_increment(&self) -> Result<(), ExclusivityViolation> {
+			compare_exchange_increment(self.refcount(), true, Ordering::Relaxed).map(drop)
+		}
+
+		/// Increments the reference count with [`Ordering::Relaxed`] iff it isn't zero,
+		/// i.e. iff the instance is currently managed by reference-counting pointers,
+		/// and returns whether the reference count was incremented.
+		///
+		/// # Errors
 		///
 		/// Iff called during exclusivity.
 		///
 		/// # Aborts
 		///
-		/// This function may abort in cases where the reference count becomes VERY high (for the given target platform),
-		/// or during a race condition when dropping an [`Exclusivity`] erroneously while this function executes.
-		fn increment(&self) {
-			#[cfg(feature = "sync")]
-			{
-				let old_count = self.refcount().fetch_add(1, Ordering::Relaxed);
-				if old_count >= (isize::MAX as usize) {
-					if old_count >= EXCLUSIVITY_MARKER {
-						// This is actually a handle clone during an exclusive borrow.
-						// We'll revert the refcount and panic instead of aborting.
-						// (TODO: Examine performance implications of having this branch here.)
-						if self.refcount().fetch_sub(1, Ordering::Relaxed) > EXCLUSIVITY_MARKER {
-							panic!("Tried to clone smart pointer during exclusive value borrow.")
-						} else {
-							// We likely got outraced by an `Exclusivity` drop.
-							// That's quite badly erroneous and could cause data corruption elsewhere
-							// due to the now most likely invalid reference count.
-							abort()
-						}
-					} else {
-						// See `alloc::Sync::Arc`'s clone implementation for why it's necessary to guard against immense reference counts:
-						// <https://github.com/rust-lang/rust/blob/81117ff930fbf3792b4f9504e3c6bccc87b10823/library/alloc/src/sync.rs#L1327-L1338>
-						//
-						// In short:
-						//
-						// An overflow could cause a use-after free. There likely aren't about `isize::MAX` threads that can race here, though, and `isize::MAX` is a decently high limit.
-						abort()
-					}
-				}
-			}
-			#[cfg(not(feature = "sync"))]
-			{
-				let old_count = self.refcount().get();
-				if old_count >= EXCLUSIVITY_MARKER - 1 {
-					if old_count < EXCLUSIVITY_MARKER {
-						// See `alloc::rc::RcInnerPtr::inc_strong`:
-						// <https://github.com/rust-lang/rust/blob/81117ff930fbf3792b4f9504e3c6bccc87b10823/library/alloc/src/rc.rs#L2442-L2453>
-						abort()
-					} else {
-						// This is actually a handle clone during an exclusive borrow.
-						// We'll panic instead of aborting. (TODO: Examine performance implications of having this branch here.)
-						panic!("Tried to clone smart pointer during exclusive value borrow.")
-					}
-				}
-				self.refcount().set(old_count + 1)
-			};
+		/// Iff the reference count becomes VERY high (for the given target platform).
+		fn try_increment_if_nonzero(&self) -> Result<bool, ExclusivityViolation> {
+			compare_exchange_increment(self.refcount(), false, Ordering::Relaxed)
 		}
 
 		/// Decrements the reference count with [`Ordering::Release`] and
@@ -265,26 +1555,25 @@ pub mod ref_counter_api {
 		/// or [`Rc::from_raw`](`crate::Rc::from_raw`) (whichever is safer)
 		/// and then dropping the resulting instance.
 		#[inline]
-		unsafe fn decrement(&self) -> DecrementFollowup {
-			match {
-				#[cfg(feature = "sync")]
-				{
-					self.refcount().fetch_sub(1, Ordering::Release)
-				}
-				#[cfg(not(feature = "sync"))]
-				{
-					let old_count = self.refcount().get();
-					self.refcount().set(old_count - 1);
-					old_count
-				}
-			} {
-				1 => {
-					#[cfg(feature = "sync")]
-					self.refcount().load(Ordering::Acquire);
-					DecrementFollowup::DropOrMoveIt
-				}
-				_ => DecrementFollowup::LeakIt,
-			}
+		unsafe fn decrement(&self) -> DecrementFollowup
+		where
+			Self: UnbiasedRefCounter,
+		{
+			self.decrement_n(1)
+		}
+
+		/// Decrements the reference count by `n` at once with [`Ordering::Release`],
+		/// e.g. to give up `n` handles to the same instance.
+		///
+		/// # Safety
+		///
+		/// See [`RefCounterExt::decrement`], for `n` references.
+		#[inline]
+		unsafe fn decrement_n(&self, n: usize) -> DecrementFollowup
+		where
+			Self: UnbiasedRefCounter,
+		{
+			decrement_n(self, n)
 		}
 
 		/// Decrements the reference count with [`Ordering::Relaxed`] and
@@ -302,44 +1591,112 @@ pub mod ref_counter_api {
 		///
 		/// Calling this method is equivalent to calling [`Rc::from_raw`](`crate::Rc::from_raw`)
 		/// and then dropping the resulting instance.
-		unsafe fn decrement_relaxed(&self) -> DecrementFollowup {
-			match {
-				#[cfg(feature = "sync")]
-				{
-					self.refcount().fetch_sub(1, Ordering::Relaxed)
-				}
-				#[cfg(not(feature = "sync"))]
+		unsafe fn decrement_relaxed(&self) -> DecrementFollowup
+		where
+			Self: UnbiasedRefCounter,
+		{
+			decrement_relaxed(self)
+		}
+
+		/// Decrements the reference count from `1` to `0` with [`Ordering::Acquire`],
+		/// iff it is exactly `1`, and returns whether that was the case.
+		///
+		/// Unlike [`RefCounterExt::decrement`], this never gives up a shared reference.
+		///
+		/// # Safety
+		///
+		/// Must not be called during exclusivity.
+		///
+		/// On success, the caller becomes responsible for moving or dropping the payload
+		/// and then calling [`RefCounterExt::release_allocation`], as after [`DecrementFollowup::DropOrMoveIt`].
+		#[must_use]
+		unsafe fn try_decrement_last(&self) -> bool {
+			self.refcount()
+				.compare_exchange(1, 0, Ordering::Acquire, Ordering::Relaxed)
+				.is_ok()
+		}
+
+		/// Decrements the reference count from `1` to `0` with [`Ordering::Relaxed`],
+		/// iff it is exactly `1`, and returns whether that was the case.
+		///
+		/// # Safety Notes
+		///
+		/// This is only suitable for synchronous reference-counting.
+		///
+		/// # Safety
+		///
+		/// See [`RefCounterExt::try_decrement_last`].
+		#[must_use]
+		unsafe fn try_decrement_last_relaxed(&self) -> bool {
+			self.refcount()
+				.compare_exchange(1, 0, Ordering::Relaxed, Ordering::Relaxed)
+				.is_ok()
+		}
+
+		/// Decrements the reference count from `1` to `0` with [`Ordering::Acquire`],
+		/// iff it is exactly `1` and there are no weak references, and returns whether that was the case.
+		///
+		/// The weak reference count is locked meanwhile, so that no weak references can be created
+		/// (see [`WeakRefCounterExt::downgrade`]) and the reference count is only ever zero on success.
+		///
+		/// On success, the counter is left in its initial state, so the allocation can be reused as-is.
+		///
+		/// # Safety
+		///
+		/// Must not be called during exclusivity.
+		///
+		/// On success, the caller becomes responsible for the payload *and its allocation*.
+		/// [`RefCounterExt::release_allocation`] must not be called in that case.
+		#[must_use]
+		unsafe fn try_decrement_unique(&self) -> bool {
+			let weak_refcount = self.weak_refcount();
+			if let Some(weak_refcount) = weak_refcount {
+				if weak_refcount
+					.compare_exchange(1, WEAK_LOCKED, Ordering::Acquire, Ordering::Relaxed)
+					.is_err()
 				{
-					let old_count = self.refcount().get();
-					self.refcount().set(old_count - 1);
-					old_count
+					return false;
 				}
-			} {
-				EXCLUSIVITY_MARKER..=usize::MAX => abort(),
-				1 => DecrementFollowup::DropOrMoveIt,
-				_ => DecrementFollowup::LeakIt,
 			}
+
+			let unique = self.try_decrement_last();
+
+			if let Some(weak_refcount) = weak_refcount {
+				weak_refcount.store(1, Ordering::Release);
+			}
+			unique
+		}
+
+		/// Decrements the reference count from `1` to `0` with [`Ordering::Relaxed`],
+		/// iff it is exactly `1` and there are no weak references, and returns whether that was the case.
+		///
+		/// # Safety Notes
+		///
+		/// This is only suitable for synchronous reference-counting.
+		///
+		/// # Safety
+		///
+		/// See [`RefCounterExt::try_decrement_unique`].
+		#[must_use]
+		unsafe fn try_decrement_unique_relaxed(&self) -> bool {
+			has_no_weak_references(self, Ordering::Relaxed) && self.try_decrement_last_relaxed()
 		}
 
-		/// Checks for exclusivity with [`Ordering::Acquire`], and, if successful, prevents reference count increments until any resulting `Exclusivity` is dropped.
+		/// Atomically checks for exclusivity (by compare-exchange, with [`Ordering::Acquire`] on success),
+		/// and, if successful, prevents reference count increments until any resulting `Exclusivity` is dropped.
 		///
 		/// Returns [`None`] iff the reference-counted instance is shared.
 		///
 		/// # Safety
 		///
-		/// Dropping the [`Exclusivity`] performs a write to a remembered address, so **the borrowed instance must not be moved** until then.
+		/// Dropping the [`Exclusivity`] performs an atomic write to a remembered address with [`Ordering::Release`],
+		/// so **the borrowed instance must not be moved** until then.
 		unsafe fn acquire(&self) -> Option<Exclusivity> {
-			match {
-				#[cfg(feature = "sync")]
-				{
-					self.refcount().load(Ordering::Acquire)
-				}
-				#[cfg(not(feature = "sync"))]
-				self.refcount().get()
-			} {
-				1 => Some(Exclusivity::new(self)),
-				_ => None,
-			}
+			let refcount = self.refcount();
+			refcount
+				.compare_exchange(1, EXCLUSIVITY_MARKER, Ordering::Acquire, Ordering::Relaxed)
+				.ok()
+				.map(|_| Exclusivity::new(refcount))
 		}
 
 		/// Checks for exclusivity with [`Ordering::Relaxed`], and, if successful, prevents reference count increments until any resulting `Exclusivity` is dropped.
@@ -358,21 +1715,258 @@ pub mod ref_counter_api {
 		/// This is only suitable for synchronous reference-counting.
 		#[must_use]
 		fn acquire_relaxed(&self) -> Option<Exclusivity> {
-			match {
-				#[cfg(feature = "sync")]
-				{
-					self.refcount().load(Ordering::Relaxed)
-				}
-				#[cfg(not(feature = "sync"))]
-				self.refcount().get()
-			} {
-				1 => Some(Exclusivity::new(self)),
-				_ => None,
+			let refcount = self.refcount();
+			refcount
+				.compare_exchange(1, EXCLUSIVITY_MARKER, Ordering::Relaxed, Ordering::Relaxed)
+				.ok()
+				.map(|_| unsafe { Exclusivity::new(refcount) })
+		}
+
+		/// To be called exactly once after following up on [`DecrementFollowup::DropOrMoveIt`],
+		/// once the payload has been dropped in place or moved out of its allocation.
+		///
+		/// Returns whether the allocation should be freed now,
+		/// which is always the case unless there are remaining weak references to it.
+		///
+		/// # Safety
+		///
+		/// Must not be called in any other situation.
+		///
+		/// Reference counters don't have drop glue,
+		/// so they may be accessed this way even after the payload was dropped in place.
+		#[must_use]
+		unsafe fn release_allocation(&self) -> bool {
+			match self.weak_refcount() {
+				None => true,
+				Some(weak_refcount) => decrement_weak_refcount(weak_refcount),
+			}
+		}
+
+		/// Resets the reference counter to its initial state,
+		/// which is the same as that of a fresh default instance.
+		///
+		/// # Safety
+		///
+		/// May only be called on the counter of a payload that was just moved out of its allocation,
+		/// as any references counted by the instance are forgotten.
+		unsafe fn reset(&self) {
+			self.refcount().store(0, Ordering::Relaxed);
+			if let Some(weak_refcount) = self.weak_refcount() {
+				weak_refcount.store(1, Ordering::Relaxed);
 			}
 		}
 	}
 	impl<T> RefCounterExt for T where T: RefCounter {}
 
+	/// Weak reference-count manipulation methods.
+	///
+	/// The weak reference-count is one higher than the number of weak references while there are any strong references,
+	/// as the strong references collectively share one weak reference.
+	pub trait WeakRefCounterExt: WeakRefCounter {
+		/// Increments the weak reference count with [`Ordering::Relaxed`].
+		///
+		/// # Aborts
+		///
+		/// Iff the weak reference count becomes VERY high (for the given target platform).
+		fn increment_weak(&self) {
+			if weak(self).fetch_add(1, Ordering::Relaxed) >= isize::MAX as usize {
+				abort()
+			}
+		}
+
+		/// Increments the weak reference count with [`Ordering::Acquire`] to create a weak reference from a strong one.
+		///
+		/// Unlike [`WeakRefCounterExt::increment_weak`], this waits while [`RefCounterExt::try_decrement_unique`] runs concurrently.
+		///
+		/// # Aborts
+		///
+		/// Iff the weak reference count becomes VERY high (for the given target platform).
+		fn downgrade(&self) {
+			let weak_refcount = weak(self);
+			let mut count = weak_refcount.load(Ordering::Relaxed);
+			loop {
+				match count {
+					WEAK_LOCKED => {
+						spin_loop();
+						count = weak_refcount.load(Ordering::Relaxed);
+						continue;
+					}
+					_ if count >= isize::MAX as usize => abort(),
+					_ => (),
+				}
+				match weak_refcount.compare_exchange_weak(
+					count,
+					count + 1,
+					Ordering::Acquire,
+					Ordering::Relaxed,
+				) {
+					Ok(_) => return,
+					Err(current) => count = current,
+				}
+			}
+		}
+
+		/// Decrements the weak reference count with [`Ordering::Release`] and
+		/// returns whether the allocation should be freed now.
+		///
+		/// # Safety
+		///
+		/// Calling this method is equivalent to dropping a [`sync::Weak`](`crate::sync::Weak`) or [`rc::Weak`](`crate::rc::Weak`)
+		/// (whichever is safer).
+		#[must_use]
+		unsafe fn decrement_weak(&self) -> bool {
+			decrement_weak_refcount(weak(self))
+		}
+
+		/// Increments the (strong) reference count iff it isn't zero, i.e. iff the payload is still alive,
+		/// and it isn't exclusively borrowed.
+		///
+		/// Returns whether the reference count was incremented.
+		///
+		/// # Aborts
+		///
+		/// Iff the reference count becomes VERY high (for the given target platform).
+		#[must_use]
+		fn upgrade(&self) -> bool {
+			// The exclusive borrow may last indefinitely, so this doesn't count as violation.
+			compare_exchange_increment(self.refcount(), false, Ordering::Acquire).unwrap_or(false)
+		}
+	}
+	impl<T> WeakRefCounterExt for T where T: WeakRefCounter {}
+
+	/// Increments `refcount` with `success` ordering, unless it is exclusively borrowed.
+	///
+	/// Returns `Ok(false)` without incrementing iff `refcount` is zero and not `from_zero`.
+	fn compare_exchange_increment<C: Count>(
+		refcount: &C,
+		from_zero: bool,
+		success: Ordering,
+	) -> Result<bool, ExclusivityViolation> {
+		let mut count = refcount.load(Ordering::Relaxed);
+		loop {
+			match count {
+				0 if !from_zero => return Ok(false),
+				EXCLUSIVITY_MARKER..=usize::MAX => {
+					return Err(ExclusivityViolation { _private: () })
+				}
+				_ if count >= C::INCREMENT_LIMIT => abort(),
+				_ => (),
+			}
+			match refcount.compare_exchange_weak(count, count + 1, success, Ordering::Relaxed) {
+				Ok(_) => return Ok(true),
+				Err(current) => count = current,
+			}
+		}
+	}
+
+	fn has_no_weak_references<T: ?Sized + RefCounter>(counter: &T, order: Ordering) -> bool {
+		match counter.weak_refcount() {
+			None => true,
+			Some(weak_refcount) => weak_refcount.load(order) == 1,
+		}
+	}
+
+	fn weak<T: ?Sized + WeakRefCounter>(counter: &T) -> &T::Count {
+		match counter.weak_refcount() {
+			Some(weak_refcount) => weak_refcount,
+			None => unreachable!(),
+		}
+	}
+
+	/// Lets the current thread count references to a fresh [`Arc`](`crate::Arc`) non-atomically,
+	/// if its reference counter supports that.
+	///
+	/// # Safety
+	///
+	/// `counter` must belong to an instance that was just placed into an [`Arc`](`crate::Arc`) with the global allocator,
+	/// whose only handle wasn't shared yet.
+	#[cfg(feature = "sync")]
+	pub(crate) unsafe fn bias<T: ?Sized + RefCounter>(counter: &T) {
+		counter.refcount().bias()
+	}
+
+	/// [`RefCounterExt::decrement_n`] for any [`RefCounter`].
+	///
+	/// # Safety
+	///
+	/// See [`RefCounterExt::decrement`], for `n` references.
+	///
+	/// The counter must not be a [`BiasedTipToe`](`crate::BiasedTipToe`) that was biased towards an owner thread.
+	pub(crate) unsafe fn decrement_n<T: ?Sized + RefCounter>(
+		counter: &T,
+		n: usize,
+	) -> DecrementFollowup {
+		match counter.refcount().fetch_sub(n, Ordering::Release) {
+			old_count if old_count == n => {
+				counter.refcount().load(Ordering::Acquire);
+				DecrementFollowup::DropOrMoveIt
+			}
+			_ => DecrementFollowup::LeakIt,
+		}
+	}
+
+	/// [`RefCounterExt::decrement_relaxed`] for any [`RefCounter`].
+	///
+	/// # Safety
+	///
+	/// See [`RefCounterExt::decrement_relaxed`].
+	///
+	/// The counter must not be a [`BiasedTipToe`](`crate::BiasedTipToe`) that was biased towards an owner thread,
+	/// which only [`Arc`](`crate::Arc`) does.
+	pub(crate) unsafe fn decrement_relaxed<T: ?Sized + RefCounter>(
+		counter: &T,
+	) -> DecrementFollowup {
+		match counter.refcount().fetch_sub(1, Ordering::Relaxed) {
+			EXCLUSIVITY_MARKER..=usize::MAX => abort(),
+			1 => DecrementFollowup::DropOrMoveIt,
+			_ => DecrementFollowup::LeakIt,
+		}
+	}
+
+	/// Like [`RefCounterExt::decrement`], but by `n` at once,
+	/// and hands the decrement to the counter's owner thread where it would otherwise be leaked.
+	///
+	/// # Safety
+	///
+	/// See [`RefCounterExt::decrement`], for `n` references.
+	#[cfg(feature = "std")]
+	pub(crate) unsafe fn decrement_or_defer<T: ?Sized + RefCounter>(
+		counter: &T,
+		n: usize,
+	) -> Result<DecrementFollowup, Deferral> {
+		Ok(
+			match counter
+				.refcount()
+				.fetch_sub_or_defer(n, Ordering::Release)?
+			{
+				old_count if old_count == n => {
+					counter.refcount().load(Ordering::Acquire);
+					DecrementFollowup::DropOrMoveIt
+				}
+				_ => DecrementFollowup::LeakIt,
+			},
+		)
+	}
+
+	/// Drops the handles that other threads handed to the current thread, iff `T` may defer them and there are any.
+	///
+	/// Call this whenever it's fine to drop unrelated payloads, after changing a reference count of type `T`.
+	#[cfg(feature = "std")]
+	pub(crate) fn drop_pending<T: ?Sized + RefCounter>() {
+		if <T::Count as Count>::DEFERS {
+			crate::biased::Owner::drop_pending()
+		}
+	}
+
+	unsafe fn decrement_weak_refcount(weak_refcount: &impl Count) -> bool {
+		if weak_refcount.fetch_sub(1, Ordering::Release) == 1 {
+			weak_refcount.load(Ordering::Acquire);
+			true
+		} else {
+			false
+		}
+	}
+
 	/// An action to take after decrementing the reference-count.
 	///
 	/// This is a recommendation rather than a fixed requirement,
@@ -390,28 +1984,38 @@ pub mod ref_counter_api {
 	///
 	/// Any attempt to clone a handle will panic until this is dropped.
 	pub struct Exclusivity {
-		refcount: *mut usize,
-		displaced_refcount: usize,
+		refcount: NonNull<()>,
+		release: unsafe fn(NonNull<()>),
 	}
 
 	impl Exclusivity {
-		fn new<T: ?Sized + Sealed>(counter: &T) -> Self {
-			let ptr = counter.refcount_ptr();
+		/// # Safety
+		///
+		/// `refcount` must have been swapped from `1` to [`EXCLUSIVITY_MARKER`] and must not move until the instance is dropped.
+		unsafe fn new<C: Count>(refcount: &C) -> Self {
 			Self {
-				displaced_refcount: unsafe { ptr.read() },
-				refcount: {
-					unsafe { ptr.write(EXCLUSIVITY_MARKER) };
-					ptr
-				},
+				refcount: NonNull::from(refcount).cast(),
+				release: release::<C>,
 			}
 		}
 	}
 
 	impl Drop for Exclusivity {
 		fn drop(&mut self) {
-			unsafe { self.refcount.write(self.displaced_refcount) }
+			unsafe { (self.release)(self.refcount) }
 		}
 	}
+
+	/// Reverts the swap to [`EXCLUSIVITY_MARKER`] with [`Ordering::Release`].
+	///
+	/// This is a subtraction rather than a store, so that [`RefCounterExt::increment`] calls racing with it
+	/// consistently abort instead of corrupting the reference count.
+	unsafe fn release<C: Count>(refcount: NonNull<()>) {
+		refcount
+			.cast::<C>()
+			.as_ref()
+			.fetch_sub(EXCLUSIVITY_MARKER - 1, Ordering::Release);
+	}
 }
 use ref_counter_api::{Exclusivity, Sealed};
 
@@ -421,6 +2025,55 @@ pub trait RefCounter: Sealed {}
 // This could be implemented over `T: Sealed`,
 // but doing so for each type individually lists them in the documentation.
 impl RefCounter for TipToe {}
+#[cfg(feature = "sync")]
+impl RefCounter for AtomicTipToe {}
+impl RefCounter for TipToes {}
+#[cfg(feature = "sync")]
+impl RefCounter for AtomicTipToes {}
+#[cfg(feature = "std")]
+impl RefCounter for BiasedTipToe {}
+#[cfg(feature = "std")]
+impl RefCounter for ShardedTipToe {}
+
+/// `(Sealed)` Implemented by [`RefCounter`]s that can safely count instances of `T`.
+///
+/// This holds for all `T`, except that [`BiasedTipToe`] requires `T: 'static`,
+/// as its handles may be dropped later on another thread.
+pub trait RefCounterFor<T: ?Sized>: RefCounter {}
+
+impl<T: ?Sized> RefCounterFor<T> for TipToe {}
+#[cfg(feature = "sync")]
+impl<T: ?Sized> RefCounterFor<T> for AtomicTipToe {}
+impl<T: ?Sized> RefCounterFor<T> for TipToes {}
+#[cfg(feature = "sync")]
+impl<T: ?Sized> RefCounterFor<T> for AtomicTipToes {}
+#[cfg(feature = "std")]
+impl<T: ?Sized + 'static> RefCounterFor<T> for BiasedTipToe {}
+#[cfg(feature = "std")]
+impl<T: ?Sized> RefCounterFor<T> for ShardedTipToe {}
+
+/// `(Sealed)` Implemented by [`RefCounter`]s whose references can be given up on any thread
+/// through [`RefCounterExt::decrement`](`ref_counter_api::RefCounterExt::decrement`) and its variants.
+///
+/// This holds for all of them except [`BiasedTipToe`],
+/// which sometimes has to hand a whole handle to its owner thread instead, which only [`Arc`] can do.
+pub trait UnbiasedRefCounter: RefCounter {}
+
+impl UnbiasedRefCounter for TipToe {}
+#[cfg(feature = "sync")]
+impl UnbiasedRefCounter for AtomicTipToe {}
+impl UnbiasedRefCounter for TipToes {}
+#[cfg(feature = "sync")]
+impl UnbiasedRefCounter for AtomicTipToes {}
+#[cfg(feature = "std")]
+impl UnbiasedRefCounter for ShardedTipToe {}
+
+/// `(Sealed)` Common trait of [`tiptoe`](`crate`)'s embeddable reference counter types that also count weak references.
+pub trait WeakRefCounter: RefCounter {}
+
+impl WeakRefCounter for TipToes {}
+#[cfg(feature = "sync")]
+impl WeakRefCounter for AtomicTipToes {}
 
 /// Enables intrusive reference counting for a structure.
 ///
@@ -435,12 +2088,15 @@ impl RefCounter for TipToe {}
 /// > The [`TipToe`] also mustn't be otherwise decremented (which can only be guaranteed if it's not public) in violation of sound reference-counting,
 /// > but that's `unsafe` anyway.
 ///
+/// [`IntrusivelyCountable::RefCounter`] must implement [`RefCounterFor<Self>`](`RefCounterFor`),
+/// which means that `Self` must be `'static` if it's `BiasedTipToe`.
+///
 /// [`IntrusivelyCountable::ref_counter`] must not have any effects, that is: It must not affect or effect any observable changes, other than through its return value.
 ///
 /// > Mainly so the callee doesn't observe its address,
 /// > which gives this crate a bit more flexibility regarding implementation details.
 pub unsafe trait IntrusivelyCountable {
-	/// [`TipToe`].
+	/// Any [`RefCounter`], e.g. [`TipToe`], [`TipToes`], [`AtomicTipToe`] or [`AtomicTipToes`].
 	type RefCounter: RefCounter;
 
 	/// Gets a reference to the instance's reference counter.
@@ -462,6 +2118,63 @@ where
 	}
 }
 
+/// [`Any`] with access to the embedded reference counter, for [`Rc<dyn IntrusiveAny>`](`Rc`) and [`Arc<dyn IntrusiveAny<AtomicTipToe>>`](`Arc`).
+///
+/// This is implemented for all [`'static`](`Any`) [`IntrusivelyCountable`] types with a matching [`RefCounter`],
+/// so that these handles can be created through [`unsize!`] and then be downcast again through
+/// [`Rc::downcast`], [`Rc::downcast_pinned`], [`Arc::downcast`], [`Arc::downcast_pinned`] or [`downcast_ref`](#method.downcast_ref).
+///
+/// # Example
+///
+/// ```rust
+/// use core::pin::Pin;
+/// use tiptoe::{unsize, IntrusiveAny, IntrusivelyCountable, Rc, TipToe};
+///
+/// struct A {
+///     counter: TipToe,
+///     value: usize,
+/// }
+///
+/// unsafe impl IntrusivelyCountable for A {
+///     type RefCounter = TipToe;
+///
+///     fn ref_counter(&self) -> &Self::RefCounter {
+///         &self.counter
+///     }
+/// }
+///
+/// let any: Pin<Rc<dyn IntrusiveAny>> = unsize!(Rc::pin(A { counter: TipToe::new(), value: 1 }) => dyn IntrusiveAny);
+/// assert_eq!(any.downcast_ref::<A>().unwrap().value, 1);
+///
+/// let a: Pin<Rc<A>> = Rc::downcast_pinned(any).ok().unwrap();
+/// ```
+pub trait IntrusiveAny<C: RefCounter = TipToe>: Any + IntrusivelyCountable<RefCounter = C> {}
+impl<T: Any + IntrusivelyCountable> IntrusiveAny<T::RefCounter> for T {}
+
+macro_rules! intrusive_any_impls {
+	($($dyn:ty),*$(,)?) => {$(
+		impl<C: RefCounter> $dyn {
+			/// Returns whether the payload is a `U`.
+			#[must_use]
+			pub fn is<U: Any>(&self) -> bool {
+				Any::type_id(self) == TypeId::of::<U>()
+			}
+
+			/// Returns a reference to the payload as `U`, iff it is a `U`.
+			#[must_use]
+			pub fn downcast_ref<U: Any>(&self) -> Option<&U> {
+				self.is::<U>()
+					.then(|| unsafe { &*ptr::addr_of!(*self).cast::<U>() })
+			}
+		}
+	)*};
+}
+intrusive_any_impls!(
+	dyn IntrusiveAny<C>,
+	dyn IntrusiveAny<C> + Send,
+	dyn IntrusiveAny<C> + Send + Sync,
+);
+
 /// Exactly like [`Clone`] but with safety restrictions regarding usage.
 ///
 /// See the methods for more information.
@@ -535,8 +2248,100 @@ impl<'a, T: ?Sized> Deref for ExclusivePin<'a, T> {
 	}
 }
 
-impl<'a, T: ?Sized> DerefMut for ExclusivePin<'a, T> {
+impl<T: ?Sized> DerefMut for ExclusivePin<'_, T> {
 	fn deref_mut(&mut self) -> &mut Self::Target {
 		&mut self.reference
 	}
 }
+
+/// The error returned when trying to create a new (strong) reference during exclusivity,
+/// that is: while an [`ExclusivePin`] or other [`Exclusivity`] guard for the same instance exists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExclusivityViolation {
+	_private: (),
+}
+
+impl Display for ExclusivityViolation {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.write_str("Tried to clone smart pointer during exclusive value borrow.")
+	}
+}
+
+/// A function that is called when a reference-counting smart pointer is [clone](`Clone::clone`)d during exclusivity.
+///
+/// See [`set_exclusivity_violation_handler`].
+pub type ExclusivityViolationHandler = fn(ExclusivityViolation) -> !;
+
+/// `null` means [`panic_on_exclusivity_violation`].
+static EXCLUSIVITY_VIOLATION_HANDLER: AtomicPtr<()> = AtomicPtr::new(ptr::null_mut());
+
+/// Sets the crate-wide behaviour of plain [`Clone::clone`] calls on
+/// reference-counting smart pointers during exclusivity.
+///
+/// The default is [`panic_on_exclusivity_violation`].
+/// Use [`abort_on_exclusivity_violation`] or a custom handler (e.g. one that logs before aborting) instead
+/// where unwinding is unacceptable.
+///
+/// Fallible clones like [`Rc::try_clone`] are not affected by this setting.
+///
+/// > This is process-global and meant to be configured once near the start of `main`,
+/// > but it's safe to change at any point.
+pub fn set_exclusivity_violation_handler(handler: ExclusivityViolationHandler) {
+	EXCLUSIVITY_VIOLATION_HANDLER.store(handler as *mut (), Ordering::Relaxed)
+}
+
+/// Panics with the [`ExclusivityViolation`] as message.
+///
+/// This is the default [`ExclusivityViolationHandler`].
+///
+/// # Panics
+///
+/// Always.
+pub fn panic_on_exclusivity_violation(violation: ExclusivityViolation) -> ! {
+	panic!("{}", violation)
+}
+
+/// Aborts the process.
+///
+/// See [`set_exclusivity_violation_handler`].
+pub fn abort_on_exclusivity_violation(_: ExclusivityViolation) -> ! {
+	abort::abort()
+}
+
+fn handle_exclusivity_violation() -> ! {
+	let handler = EXCLUSIVITY_VIOLATION_HANDLER.load(Ordering::Relaxed);
+	let handler = if handler.is_null() {
+		panic_on_exclusivity_violation
+	} else {
+		unsafe { mem::transmute::<*mut (), ExclusivityViolationHandler>(handler) }
+	};
+	handler(ExclusivityViolation { _private: () })
+}
+
+/// The error returned when a fallible constructor can't allocate memory.
+///
+/// This hands back the value that would have been moved into the new allocation.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct AllocError<T> {
+	value: T,
+}
+
+impl<T> Debug for AllocError<T> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.debug_struct("AllocError").finish_non_exhaustive()
+	}
+}
+
+impl<T> Display for AllocError<T> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.write_str("Memory allocation failed.")
+	}
+}
+
+impl<T> AllocError<T> {
+	/// Unwraps the value that couldn't be moved into a new allocation.
+	#[must_use]
+	pub fn into_inner(self) -> T {
+		self.value
+	}
+}
@@ -0,0 +1,132 @@
+//! Allocation recycling for [`Arc`](`crate::Arc`).
+//!
+//! When the reference count of an [`Arc`](`crate::Arc`)-managed instance "tips over",
+//! the payload is dropped in place and its allocation is handed back to the [`Arc`](`crate::Arc`)'s [`Allocator`].
+//! A [`Pool`] keeps a limited number of these allocations around for reuse instead of returning them to the [`Global`] allocator.
+
+use allocator_api2::alloc::{AllocError, Allocator, Global};
+use core::{
+	alloc::Layout,
+	ptr::{self, NonNull},
+	sync::atomic::{AtomicPtr, Ordering},
+};
+
+/// A thread-safe, lock-free pool of up to `CAPACITY` recycled allocations of one specific [`Layout`].
+///
+/// `&Pool` is an [`Allocator`]:
+/// Allocations with a different [`Layout`] as well as any overflow are forwarded to the [`Global`] allocator.
+///
+/// Each allocation or deallocation scans at most `CAPACITY` slots, so keep that fairly small.
+///
+/// # Example
+///
+/// ```rust
+/// use core::alloc::Layout;
+/// use tiptoe::{pool::Pool, Arc, AtomicTipToe, IntrusivelyCountable};
+///
+/// #[derive(Default)]
+/// struct Message {
+///     counter: AtomicTipToe,
+///     payload: [u8; 32],
+/// }
+///
+/// unsafe impl IntrusivelyCountable for Message {
+///     type RefCounter = AtomicTipToe;
+///
+///     fn ref_counter(&self) -> &Self::RefCounter {
+///         &self.counter
+///     }
+/// }
+///
+/// static MESSAGES: Pool<16> = Pool::new(Layout::new::<Message>());
+///
+/// let first = Arc::pin_in(Message::default(), &MESSAGES);
+/// let address: *const Message = &*first;
+/// drop(first);
+///
+/// // The allocation was recycled.
+/// let second = Arc::pin_in(Message::default(), &MESSAGES);
+/// assert!(core::ptr::eq(address, &*second));
+/// ```
+pub struct Pool<const CAPACITY: usize> {
+	layout: Layout,
+	/// Each slot is either null or an available allocation with [`Self::layout`].
+	slots: [AtomicPtr<u8>; CAPACITY],
+}
+
+impl<const CAPACITY: usize> Pool<CAPACITY> {
+	#[allow(clippy::declare_interior_mutable_const)]
+	const EMPTY: AtomicPtr<u8> = AtomicPtr::new(ptr::null_mut());
+
+	/// Creates a new empty [`Pool`] that recycles allocations with the given `layout`.
+	#[must_use]
+	pub const fn new(layout: Layout) -> Self {
+		Self {
+			layout,
+			slots: [Self::EMPTY; CAPACITY],
+		}
+	}
+
+	/// Gets the [`Layout`] of allocations recycled by this [`Pool`].
+	#[must_use]
+	pub fn layout(&self) -> Layout {
+		self.layout
+	}
+}
+
+unsafe impl<const CAPACITY: usize> Allocator for &Pool<CAPACITY> {
+	fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+		if layout == self.layout {
+			for slot in &self.slots {
+				let available = slot.load(Ordering::Relaxed);
+				if !available.is_null()
+					&& slot
+						.compare_exchange(
+							available,
+							ptr::null_mut(),
+							Ordering::Acquire,
+							Ordering::Relaxed,
+						)
+						.is_ok()
+				{
+					return Ok(unsafe {
+						NonNull::new_unchecked(ptr::slice_from_raw_parts_mut(
+							available,
+							layout.size(),
+						))
+					});
+				}
+			}
+		}
+		Global.allocate(layout)
+	}
+
+	unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+		if layout == self.layout {
+			for slot in &self.slots {
+				if slot
+					.compare_exchange(
+						ptr::null_mut(),
+						ptr.as_ptr(),
+						Ordering::Release,
+						Ordering::Relaxed,
+					)
+					.is_ok()
+				{
+					return;
+				}
+			}
+		}
+		Global.deallocate(ptr, layout)
+	}
+}
+
+impl<const CAPACITY: usize> Drop for Pool<CAPACITY> {
+	fn drop(&mut self) {
+		for slot in &mut self.slots {
+			if let Some(available) = NonNull::new(*slot.get_mut()) {
+				unsafe { Global.deallocate(available, self.layout) }
+			}
+		}
+	}
+}
@@ -0,0 +1,861 @@
+//! Single-threaded intrusively reference-counting pointers.
+
+use crate::{
+	counted::Counted,
+	ref_counter_api::{self, DecrementFollowup, RefCounterExt, WeakRefCounterExt},
+	AllocError, ExclusivePin, ExclusivityViolation, IntrusivelyCountable, ManagedClone,
+	RefCounterFor, WeakRefCounter,
+};
+use alloc::{
+	alloc::{alloc, dealloc, handle_alloc_error, Layout},
+	borrow::{Cow, ToOwned},
+	boxed::Box,
+	vec::Vec,
+};
+use core::{
+	any::{Any, TypeId},
+	borrow::Borrow,
+	convert::Infallible,
+	fmt::{self, Debug, Display, Formatter, Pointer},
+	hash::{Hash, Hasher},
+	iter::FromIterator,
+	mem::{self, MaybeUninit},
+	ops::Deref,
+	pin::Pin,
+	ptr::{self, NonNull},
+};
+use tap::{Pipe, Tap};
+
+// spell-checker:ignore eference ounted
+/// A (single-threaded) **r**eference-**c**ounted smart pointer (copy-on-write single-item container).
+///
+/// Unlike with [`alloc::rc::Rc`], the reference-count must be embedded in the payload instance itself.
+///
+/// [`Rc`] is neither [`Send`] nor [`Sync`] and only uses the relaxed (synchronous) [`RefCounterExt`] methods.
+#[repr(transparent)]
+pub struct Rc<T: ?Sized + IntrusivelyCountable> {
+	pointer: NonNull<T>,
+}
+
+impl<T: ?Sized + IntrusivelyCountable> AsRef<T> for Rc<T> {
+	fn as_ref(&self) -> &T {
+		self
+	}
+}
+
+impl<T: ?Sized + IntrusivelyCountable> Borrow<T> for Rc<T> {
+	fn borrow(&self) -> &T {
+		self
+	}
+}
+
+impl<T: ?Sized + IntrusivelyCountable> Clone for Rc<T> {
+	/// Makes a clone of this [`Rc`], pointing to the same instance.
+	///
+	/// This increases the strong reference count by 1.
+	fn clone(&self) -> Self {
+		self.ref_counter().increment();
+		Self {
+			pointer: self.pointer,
+		}
+	}
+
+	fn clone_from(&mut self, source: &Self) {
+		if !Self::ptr_eq(self, source) {
+			*self = source.clone()
+		}
+	}
+}
+
+#[cfg(feature = "nightly")]
+impl<T: ?Sized + IntrusivelyCountable, U: ?Sized + IntrusivelyCountable>
+	core::ops::CoerceUnsized<Rc<U>> for Rc<T>
+where
+	T: core::marker::Unsize<U>,
+	U: IntrusivelyCountable<RefCounter = T::RefCounter>,
+{
+}
+
+#[cfg(feature = "nightly")]
+unsafe impl<T: ?Sized + IntrusivelyCountable> core::pin::PinCoerceUnsized for Rc<T> {}
+
+impl<T: ?Sized + IntrusivelyCountable> Debug for Rc<T>
+where
+	T: Debug,
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.debug_tuple("Rc").field(&&**self).finish()
+	}
+}
+
+impl<T: IntrusivelyCountable> Default for Rc<T>
+where
+	T: Default,
+{
+	fn default() -> Self {
+		Self::new(T::default())
+	}
+}
+
+impl<T: ?Sized + IntrusivelyCountable> Deref for Rc<T> {
+	type Target = T;
+
+	fn deref(&self) -> &Self::Target {
+		unsafe { self.pointer.as_ref() }
+	}
+}
+
+impl<T: ?Sized + IntrusivelyCountable> Display for Rc<T>
+where
+	T: Display,
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		(**self).fmt(f)
+	}
+}
+
+impl<T: ?Sized + IntrusivelyCountable> Drop for Rc<T> {
+	fn drop(&mut self) {
+		unsafe {
+			// `Rc` never biases its counter.
+			match ref_counter_api::decrement_relaxed(self.ref_counter()) {
+				DecrementFollowup::LeakIt => (),
+				DecrementFollowup::DropOrMoveIt => {
+					let layout = Layout::for_value(self.pointer.as_ref());
+					let ref_counter: *const T::RefCounter = self.ref_counter();
+					ptr::drop_in_place(self.pointer.as_ptr());
+					if (*ref_counter).release_allocation() {
+						dealloc(self.pointer.as_ptr().cast(), layout)
+					}
+				}
+			}
+		}
+	}
+}
+
+impl<T: ?Sized + IntrusivelyCountable> Eq for Rc<T> where T: Eq {}
+
+impl<T: ?Sized + IntrusivelyCountable> From<Box<T>> for Rc<T> {
+	/// Converts a [`Box`] into an [`Rc`] without reallocating.
+	fn from(box_: Box<T>) -> Self {
+		box_.ref_counter().increment();
+		unsafe { Self::from_raw(NonNull::new_unchecked(Box::leak(box_))) }
+	}
+}
+
+impl<'a, B: ?Sized + IntrusivelyCountable> From<Cow<'a, B>> for Rc<B>
+where
+	B: ToOwned,
+	Rc<B>: From<B::Owned>,
+{
+	/// Always converts into an exclusive instance,
+	/// either by copying or by moving the value.
+	fn from(cow: Cow<'a, B>) -> Self {
+		match cow {
+			Cow::Borrowed(b) => b.to_owned().into(),
+			Cow::Owned(o) => o.into(),
+		}
+	}
+}
+
+impl<T, C: RefCounterFor<[T]> + Default> From<Vec<T>> for Rc<Counted<[T], C>> {
+	/// Moves the items of `vec` into a new allocation.
+	fn from(vec: Vec<T>) -> Self {
+		Box::<Counted<[T], C>>::from(vec).into()
+	}
+}
+
+impl<T: Clone, C: RefCounterFor<[T]> + Default> From<&[T]> for Rc<Counted<[T], C>> {
+	fn from(slice: &[T]) -> Self {
+		Box::<Counted<[T], C>>::from(slice).into()
+	}
+}
+
+impl<C: RefCounterFor<str> + Default> From<&str> for Rc<Counted<str, C>> {
+	fn from(str: &str) -> Self {
+		Box::<Counted<str, C>>::from(str).into()
+	}
+}
+
+impl<T: Sized + IntrusivelyCountable> From<T> for Rc<T> {
+	fn from(value: T) -> Self {
+		Self::new(value)
+	}
+}
+
+impl<T: Sized + IntrusivelyCountable> From<T> for Pin<Rc<T>> {
+	fn from(value: T) -> Self {
+		Rc::pin(value)
+	}
+}
+
+impl<T: ?Sized + IntrusivelyCountable> From<Pin<Rc<T>>> for Rc<T>
+where
+	T: Unpin,
+{
+	fn from(pinned: Pin<Rc<T>>) -> Self {
+		unsafe { Pin::into_inner_unchecked(pinned) }
+	}
+}
+
+impl<T: ?Sized + IntrusivelyCountable> From<Rc<T>> for Pin<Rc<T>>
+where
+	T: Unpin,
+{
+	fn from(unpinned: Rc<T>) -> Self {
+		unsafe { Pin::new_unchecked(unpinned) }
+	}
+}
+
+impl<T, C: RefCounterFor<[T]> + Default> FromIterator<T> for Rc<Counted<[T], C>> {
+	/// Allocates only once iff `iter`'s [size hint](`Iterator::size_hint`) is exact and accurate,
+	/// and otherwise collects the items into a [`Vec`] first.
+	fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+		iter.into_iter().collect::<Box<Counted<[T], C>>>().into()
+	}
+}
+
+impl<T: ?Sized + IntrusivelyCountable> Hash for Rc<T>
+where
+	T: Hash,
+{
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		(**self).hash(state)
+	}
+}
+
+impl<T: ?Sized + IntrusivelyCountable> Ord for Rc<T>
+where
+	T: Ord,
+{
+	fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+		(**self).cmp(other)
+	}
+}
+
+impl<T: ?Sized + IntrusivelyCountable, O: ?Sized + IntrusivelyCountable> PartialEq<Rc<O>> for Rc<T>
+where
+	T: PartialEq<O>,
+{
+	fn eq(&self, other: &Rc<O>) -> bool {
+		(**self) == (**other)
+	}
+}
+
+impl<T: ?Sized + IntrusivelyCountable, O: ?Sized + IntrusivelyCountable> PartialOrd<Rc<O>> for Rc<T>
+where
+	T: PartialOrd<O>,
+{
+	fn partial_cmp(&self, other: &Rc<O>) -> Option<core::cmp::Ordering> {
+		(**self).partial_cmp(other)
+	}
+}
+
+impl<T: ?Sized + IntrusivelyCountable> Pointer for Rc<T> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		Pointer::fmt(&self.pointer, f)
+	}
+}
+
+impl<T: ?Sized + IntrusivelyCountable> Unpin for Rc<T> {}
+
+impl<T: ?Sized + IntrusivelyCountable> Rc<T> {
+	/// Creates a new instance of [`Rc<_>`] by moving `value` into a new heap allocation.
+	///
+	/// This increases the intrusive reference-count by 1.
+	///
+	/// Calling this method with an instance with non-zero reference-count is safe,
+	/// but likely to lead to memory leaks (or the process being aborted, if the recorded count is very high).
+	#[must_use]
+	pub fn new(value: T) -> Self
+	where
+		T: Sized,
+	{
+		Self::try_new(value).unwrap_or_else(|_| handle_alloc_error(Layout::new::<T>()))
+	}
+
+	/// Creates a new instance of [`Pin<Rc<_>>`](`Rc`) by moving `value` into a new heap allocation.
+	///
+	/// This increases the intrusive reference-count by 1.
+	///
+	/// Calling this method with an instance with non-zero reference-count is safe,
+	/// but likely to lead to memory leaks (or the process being aborted, if the recorded count is very high).
+	#[must_use]
+	pub fn pin(value: T) -> Pin<Self>
+	where
+		T: Sized,
+	{
+		unsafe { Pin::new_unchecked(Self::new(value)) }
+	}
+
+	/// Creates a new instance of [`Rc<_>`] by moving `value` into a new heap allocation, without aborting if that fails.
+	///
+	/// This increases the intrusive reference-count by 1.
+	///
+	/// Calling this method with an instance with non-zero reference-count is safe,
+	/// but likely to lead to memory leaks (or the process being aborted, if the recorded count is very high).
+	///
+	/// # Errors
+	///
+	/// Iff the allocation fails, in which case `value` is handed back through the [`AllocError`].
+	pub fn try_new(value: T) -> Result<Self, AllocError<T>>
+	where
+		T: Sized,
+	{
+		match allocate_uninit::<T>() {
+			Some(mut uninit) => unsafe {
+				uninit.as_mut_ptr().write(value);
+				Ok(Self::from_uninit(uninit))
+			},
+			None => Err(AllocError { value }),
+		}
+	}
+
+	/// Creates a new instance of [`Pin<Rc<_>>`](`Rc`) by moving `value` into a new heap allocation, without aborting if that fails.
+	///
+	/// This increases the intrusive reference-count by 1.
+	///
+	/// Calling this method with an instance with non-zero reference-count is safe,
+	/// but likely to lead to memory leaks (or the process being aborted, if the recorded count is very high).
+	///
+	/// # Errors
+	///
+	/// Iff the allocation fails, in which case `value` is handed back through the [`AllocError`].
+	pub fn try_pin(value: T) -> Result<Pin<Self>, AllocError<T>>
+	where
+		T: Sized,
+	{
+		Self::try_new(value).map(|this| unsafe { Pin::new_unchecked(this) })
+	}
+
+	/// Creates a new instance of [`Pin<Rc<_>>`](`Rc`) by initialising its payload in place in a new heap allocation.
+	///
+	/// See [`Rc::try_pin_init`] for details.
+	///
+	/// # Safety
+	///
+	/// `init` must fully initialise the payload.
+	#[must_use]
+	pub unsafe fn pin_init(init: impl FnOnce(Pin<&mut MaybeUninit<T>>)) -> Pin<Self>
+	where
+		T: Sized,
+	{
+		match Self::try_pin_init(|uninit| {
+			init(uninit);
+			Ok::<_, Infallible>(())
+		}) {
+			Ok(this) => this,
+			Err(infallible) => match infallible {},
+		}
+	}
+
+	/// Creates a new instance of [`Pin<Rc<_>>`](`Rc`) by fallibly initialising its payload in place in a new heap allocation.
+	///
+	/// Unlike with [`Rc::pin`], the payload is never moved,
+	/// so large or self-referential values don't have to be built on the stack first.
+	///
+	/// The memory passed to `init` is already pinned.
+	/// The intrusive reference-count is increased by 1 only once `init` returns [`Ok`].
+	///
+	/// # Errors
+	///
+	/// Iff `init` fails, its error is returned and the allocation is freed again.
+	/// (The allocation is also freed if `init` panics.)
+	///
+	/// # Safety
+	///
+	/// `init` must fully initialise the payload iff it returns [`Ok`].
+	///
+	/// Initialising the payload with a non-zero reference-count is sound,
+	/// but likely to lead to memory leaks (or the process being aborted, if the recorded count is very high).
+	pub unsafe fn try_pin_init<E>(
+		init: impl FnOnce(Pin<&mut MaybeUninit<T>>) -> Result<(), E>,
+	) -> Result<Pin<Self>, E>
+	where
+		T: Sized,
+	{
+		// Dropping this on error or panic frees the allocation without dropping the payload.
+		let mut uninit =
+			allocate_uninit::<T>().unwrap_or_else(|| handle_alloc_error(Layout::new::<T>()));
+		init(Pin::new_unchecked(&mut *uninit))?;
+		Ok(Pin::new_unchecked(Self::from_uninit(uninit)))
+	}
+
+	/// Converts the initialised allocation `uninit` into an [`Rc`] without reallocating, like [`From<Box<T>>`].
+	///
+	/// # Safety
+	///
+	/// `uninit` must be fully initialised.
+	unsafe fn from_uninit(uninit: Box<MaybeUninit<T>>) -> Self
+	where
+		T: Sized,
+	{
+		Box::from_raw(Box::into_raw(uninit).cast::<T>()).into()
+	}
+
+	/// # Errors
+	///
+	/// Iff this [`Rc`] is not an exclusive handle.
+	pub fn try_unpin(this: Pin<Self>) -> Result<T, Pin<Self>>
+	where
+		T: Sized + Unpin,
+	{
+		Pin::into_inner(this)
+			.pipe(Self::try_unwrap)
+			.map_err(Pin::new)
+	}
+
+	/// # Errors
+	///
+	/// Iff this [`Rc`] is not an exclusive handle.
+	pub fn try_unwrap(this: Self) -> Result<T, Self>
+	where
+		T: Sized,
+	{
+		if !unsafe { this.ref_counter().try_decrement_last_relaxed() } {
+			return Err(this);
+		}
+
+		// The reference count is now zero, so (unlike with an `Exclusivity`) no handle can be cloned or upgraded anymore.
+		Ok(unsafe { Self::move_out(Self::leak(this)) })
+	}
+
+	/// Unwraps the payload iff this is the last [`Rc`] pointing to it, and otherwise just drops this handle.
+	///
+	/// This is equivalent to [`Rc::try_unwrap`] followed by a drop on failure,
+	/// as with [`alloc::rc::Rc::into_inner`].
+	#[must_use]
+	pub fn into_inner(this: Self) -> Option<T>
+	where
+		T: Sized,
+	{
+		let followup = unsafe { ref_counter_api::decrement_relaxed(this.ref_counter()) };
+		let pointer = Self::leak(this);
+		match followup {
+			DecrementFollowup::LeakIt => None,
+			DecrementFollowup::DropOrMoveIt => Some(unsafe { Self::move_out(pointer) }),
+		}
+	}
+
+	/// Unwraps the payload iff this is the last [`Rc`] pointing to it, and otherwise clones it.
+	#[must_use]
+	pub fn unwrap_or_clone(this: Self) -> T
+	where
+		T: Sized + ManagedClone,
+	{
+		Self::try_unwrap(this).unwrap_or_else(|this| unsafe {
+			// Safety:
+			// The payload of an unpinned `Rc` may be moved out of it, so a bare clone is equally encapsulated.
+			T::managed_clone(&this)
+		})
+	}
+
+	/// Moves the payload out of its allocation, which is freed unless there are weak handles to it,
+	/// and resets its reference counter.
+	///
+	/// # Safety
+	///
+	/// Must be called exactly once, after following up on [`DecrementFollowup::DropOrMoveIt`].
+	unsafe fn move_out(pointer: NonNull<T>) -> T
+	where
+		T: Sized,
+	{
+		let layout = Layout::for_value(pointer.as_ref());
+		let ref_counter: *const T::RefCounter = pointer.as_ref().ref_counter();
+		let value = pointer.as_ptr().read();
+		if (*ref_counter).release_allocation() {
+			dealloc(pointer.as_ptr().cast(), layout)
+		}
+		value.tap_mut(|unwrapped| unwrapped.ref_counter().reset())
+	}
+
+	/// Constructs an [`Rc`] instance from a compatible value pointer.
+	///
+	/// # Safety
+	///
+	/// The pointer `raw_value` must have been created by leaking the heap-allocated value in a compatible *unpinned* container.
+	///
+	/// Containers are incompatible if their type parameter differs in a way that
+	/// makes the equivalent pointer reinterpretation cast invalid.
+	/// Otherwise:
+	///
+	/// ([`Rc`] and [`Arc`](`crate::Arc`) are compatible.
+	/// [`Box`] is compatible iff the internal reference count had been incremented to at least `1` at the time of leaking.)
+	///
+	/// For every time the instance that pointer points to was leaked,
+	/// this function must be called at most once.
+	///
+	/// The data `raw_value` points to may be in use only by [`Rc`].
+	#[must_use = "Implicitly dropping this handle is likely a mistake."]
+	pub unsafe fn from_raw(raw_value: NonNull<T>) -> Self {
+		debug_assert_ne!(
+			raw_value.as_ptr().cast::<()>() as usize,
+			0,
+			"Called `tiptoe::Rc::from_raw` with null pointer."
+		);
+		Self { pointer: raw_value }
+	}
+
+	/// Constructs a [pinned](`core::pin`) [`Rc`] instance from a compatible value pointer.
+	///
+	/// # Safety
+	///
+	/// The pointer `raw_value` must have been created by leaking the heap-allocated value in a compatible container.
+	///
+	/// Containers are incompatible if their type parameter differs in a way that
+	/// makes the equivalent pointer reinterpretation cast invalid.
+	/// Otherwise:
+	///
+	/// ([`Rc`] and [`Arc`](`crate::Arc`) are compatible.
+	/// [`Box`] is compatible iff the internal reference count had been incremented to at least `1` at the time of leaking.)
+	///
+	/// For every time the instance that pointer points to was leaked,
+	/// this function must be called at most once.
+	///
+	/// The data `raw_value` points to may be in use only by [`Rc`].
+	#[must_use = "Implicitly dropping this handle is likely a mistake."]
+	pub unsafe fn pinned_from_raw(raw_value: NonNull<T>) -> Pin<Self> {
+		debug_assert_ne!(
+			raw_value.as_ptr().cast::<()>() as usize,
+			0,
+			"Called `tiptoe::Rc::from_raw` with null pointer."
+		);
+		Self { pointer: raw_value }.pipe(|this| Pin::new_unchecked(this))
+	}
+
+	/// Unsafely borrows a shared reference to an [`Rc`]-managed instance as [`Rc`].
+	///
+	/// This is purely a reinterpretation cast.
+	///
+	/// # Safety
+	///
+	/// `inner` must be a reference to a reference to an instance managed by [`Rc`].
+	#[must_use]
+	pub unsafe fn borrow_from_inner_ref<'a>(inner: &'a &'a T) -> &'a Self {
+		&*ptr::addr_of!(*inner).cast::<Self>()
+	}
+
+	/// Unsafely borrows a shared reference to a [`Pin<Rc>`]-managed instance as [`Pin<Rc>`].
+	///
+	/// This is purely a reinterpretation cast.
+	///
+	/// # Safety
+	///
+	/// `inner` must be a reference to a reference to an instance managed by [`Pin<Rc>`].
+	#[must_use]
+	pub unsafe fn borrow_pin_from_inner_ref<'a>(inner: &'a &'a T) -> &'a Pin<Self> {
+		&*ptr::addr_of!(*inner).cast::<Pin<Self>>()
+	}
+
+	/// Unwraps the payload pointer contained in the current instance.
+	///
+	/// This does not decrease the reference-count.
+	#[must_use = "Ignoring this pointer will usually lead to the underlying payload instance leaking."]
+	pub fn leak(this: Self) -> NonNull<T> {
+		let pointer = this.pointer;
+		mem::forget(this);
+		pointer
+	}
+
+	/// Unwraps the payload pointer contained in the current instance.
+	///
+	/// This does not decrease the reference-count.
+	///
+	/// # Safety Notes
+	///
+	/// Keep in mind that the pinning invariants, including the drop guarantee, must still be upheld.
+	#[must_use = "Ignoring this pointer will usually lead to the underlying payload instance leaking."]
+	pub fn leak_pinned(this: Pin<Self>) -> NonNull<T> {
+		let this = unsafe { Pin::into_inner_unchecked(this) };
+		let pointer = this.pointer;
+		mem::forget(this);
+		pointer
+	}
+
+	/// Converts this [`Rc`] into a [`Box`] without reallocating, iff it is the only handle to its payload.
+	///
+	/// The intrusive reference-count is reset to 0, as [`Rc::from`] a [`Box`] increments it again.
+	///
+	/// # Errors
+	///
+	/// Iff this [`Rc`] is not the only (strong or weak) handle to its payload.
+	pub fn try_into_box(this: Self) -> Result<Box<T>, Self> {
+		if unsafe { this.ref_counter().try_decrement_unique_relaxed() } {
+			Ok(unsafe { Box::from_raw(Self::leak(this).as_ptr()) })
+		} else {
+			Err(this)
+		}
+	}
+
+	/// Converts this [`Pin<Rc>`] into a [`Pin<Box>`] without reallocating, iff it is the only handle to its payload.
+	///
+	/// The intrusive reference-count is reset to 0, as [`Rc::from`] a [`Box`] increments it again.
+	///
+	/// # Errors
+	///
+	/// Iff this [`Rc`] is not the only (strong or weak) handle to its payload.
+	pub fn try_into_pin_box(this: Pin<Self>) -> Result<Pin<Box<T>>, Pin<Self>> {
+		unsafe {
+			Self::try_into_box(Pin::into_inner_unchecked(this))
+				.map(|box_| Pin::new_unchecked(box_))
+				.map_err(|this| Pin::new_unchecked(this))
+		}
+	}
+
+	/// Creates a new [`Weak`] handle to the payload of this [`Pin<Rc<T>>`](`Rc`).
+	///
+	/// This increases the intrusive weak reference-count by 1.
+	#[must_use]
+	pub fn downgrade(this: &Pin<Self>) -> Weak<T>
+	where
+		T: Sized,
+		T::RefCounter: WeakRefCounter,
+	{
+		this.ref_counter().increment_weak();
+		Weak {
+			pointer: unsafe { (*ptr::addr_of!(*this).cast::<Self>()).pointer },
+			ref_counter: this.ref_counter().into(),
+		}
+	}
+
+	/// Checks whether two instances of [`Rc<T>`] point to the same instance.
+	#[must_use]
+	pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+		ptr::eq(this.pointer.as_ptr(), other.pointer.as_ptr())
+	}
+
+	/// Ensures the payload is exclusively pointed to by this [`Rc<T>`], cloning it if necessary,
+	/// and gives access to a [`Pin<&mut T>`] that safely can *not* be used to clone the [`Rc<T>`].
+	///
+	/// # Panics
+	///
+	/// Iff [`ManagedClone::managed_clone`] returns an instance with a non-zero reference-count.
+	pub fn make_mut(this: &mut Pin<Self>) -> ExclusivePin<'_, T>
+	where
+		T: Sized + ManagedClone,
+	{
+		let exclusivity = this.ref_counter().acquire_relaxed().unwrap_or_else(|| {
+			*this = unsafe {
+				// Safety:
+				// No effective encapsulation change happens.
+				// `Self::pin` does call `IntrusivelyCountable::ref_counter`, but this is legal as that method is not allowed to have effects.
+				(**this).managed_clone().pipe(Self::pin)
+			};
+
+			// This could be done faster, but whether that's significant is up to benchmarking it.
+			this.ref_counter().acquire_relaxed().expect("unreachable")
+		});
+
+		ExclusivePin::new(exclusivity, unsafe {
+			Pin::new_unchecked((*ptr::addr_of_mut!(*this).cast::<Self>()).pointer.as_mut())
+		})
+	}
+
+	/// Makes a clone of this [`Rc`], pointing to the same instance, unless it is exclusively borrowed.
+	///
+	/// # Errors
+	///
+	/// Iff called during exclusivity, e.g. on a handle obtained through [`Rc::borrow_from_inner_ref`]
+	/// while an [`ExclusivePin`] for the same instance exists.
+	///
+	/// Unlike [`Clone::clone`], this isn't affected by [`set_exclusivity_violation_handler`](`crate::set_exclusivity_violation_handler`).
+	pub fn try_clone(this: &Self) -> Result<Self, ExclusivityViolation> {
+		this.ref_counter().try_increment()?;
+		Ok(Self {
+			pointer: this.pointer,
+		})
+	}
+
+	/// Makes a clone of this [`Pin<Rc>`](`Pin`), pointing to the same instance, unless it is exclusively borrowed.
+	///
+	/// # Errors
+	///
+	/// Iff called during exclusivity, e.g. on a handle obtained through [`Rc::borrow_pin_from_inner_ref`]
+	/// while an [`ExclusivePin`] for the same instance exists.
+	pub fn try_clone_pinned(this: &Pin<Self>) -> Result<Pin<Self>, ExclusivityViolation> {
+		this.ref_counter().try_increment()?;
+		Ok(unsafe {
+			Pin::new_unchecked(Self {
+				pointer: (*ptr::addr_of!(*this).cast::<Self>()).pointer,
+			})
+		})
+	}
+
+	/// Checks whether the payload is exclusively pointed to by this [`Rc<T>`] and, if this is the case,
+	/// gives access to a [`Pin<&mut T>`] that safely can *not* be used to clone the [`Rc<T>`].
+	#[must_use]
+	pub fn get_mut(this: &mut Pin<Self>) -> Option<ExclusivePin<'_, T>> {
+		this.ref_counter().acquire_relaxed().map(|exclusivity| {
+			ExclusivePin::new(exclusivity, unsafe {
+				Pin::new_unchecked((*ptr::addr_of_mut!(*this).cast::<Self>()).pointer.as_mut())
+			})
+		})
+	}
+
+	/// Attempts to cast this [`Rc`] into once of concrete type `U`.
+	///
+	/// # Errors
+	///
+	/// Iff the underlying instance isn't a `U`.
+	pub fn downcast<U>(this: Self) -> Result<Rc<U>, Self>
+	where
+		T: Any,
+		U: Any + IntrusivelyCountable,
+	{
+		if Any::type_id(&*this) == TypeId::of::<U>() {
+			Ok(unsafe { Rc::from_raw(Rc::leak(this).cast()) })
+		} else {
+			Err(this)
+		}
+	}
+
+	/// Attempts to cast this [`Rc`] into once of concrete type `U`.
+	///
+	/// # Errors
+	///
+	/// Iff the underlying instance isn't a `U`.
+	pub fn downcast_pinned<U>(this: Pin<Self>) -> Result<Pin<Rc<U>>, Pin<Self>>
+	where
+		T: Any,
+		U: Any + IntrusivelyCountable,
+	{
+		if Any::type_id(&*this) == TypeId::of::<U>() {
+			Ok(unsafe { Rc::pinned_from_raw(Rc::leak_pinned(this).cast()) })
+		} else {
+			Err(this)
+		}
+	}
+
+	/// Converts this [`Rc`] into one of a (usually) dynamically sized type `U`, like `dyn Trait`.
+	///
+	/// Prefer the safe [`unsize!`](`crate::unsize`) macro where possible.
+	///
+	/// # Safety
+	///
+	/// `unsize` must return its argument with only its pointer metadata changed, e.g. through an unsizing coercion,
+	/// and [`U::ref_counter`](`IntrusivelyCountable::ref_counter`) must return the same reference counter as [`T::ref_counter`](`IntrusivelyCountable::ref_counter`).
+	pub unsafe fn unsize<U>(this: Self, unsize: impl FnOnce(*mut T) -> *mut U) -> Rc<U>
+	where
+		U: ?Sized + IntrusivelyCountable<RefCounter = T::RefCounter>,
+	{
+		let pointer = Self::leak(this);
+		let unsized_ = unsize(pointer.as_ptr());
+		debug_assert_eq!(unsized_.cast::<()>(), pointer.as_ptr().cast::<()>());
+		Rc::from_raw(NonNull::new_unchecked(unsized_))
+	}
+
+	/// Converts this [`Pin<Rc>`] into one of a (usually) dynamically sized type `U`, like `dyn Trait`.
+	///
+	/// Prefer the safe [`unsize!`](`crate::unsize`) macro where possible.
+	///
+	/// # Safety
+	///
+	/// See [`Rc::unsize`].
+	pub unsafe fn unsize_pinned<U>(
+		this: Pin<Self>,
+		unsize: impl FnOnce(*mut T) -> *mut U,
+	) -> Pin<Rc<U>>
+	where
+		U: ?Sized + IntrusivelyCountable<RefCounter = T::RefCounter>,
+	{
+		Pin::new_unchecked(Self::unsize(Pin::into_inner_unchecked(this), unsize))
+	}
+}
+
+/// Allocates uninitialised memory for a `T` without aborting on failure.
+fn allocate_uninit<T>() -> Option<Box<MaybeUninit<T>>> {
+	let layout = Layout::new::<T>();
+	if layout.size() == 0 {
+		return Some(unsafe { Box::from_raw(NonNull::dangling().as_ptr()) });
+	}
+
+	let memory = unsafe { alloc(layout) }.cast::<MaybeUninit<T>>();
+	if memory.is_null() {
+		None
+	} else {
+		Some(unsafe { Box::from_raw(memory) })
+	}
+}
+
+/// A weak handle to a [`Pin<Rc<T>>`](`Rc`)-managed instance.
+///
+/// This keeps the allocation, but not the payload, alive.
+/// The reference counter itself remains accessible after the payload was dropped.
+pub struct Weak<T: IntrusivelyCountable>
+where
+	T::RefCounter: WeakRefCounter,
+{
+	pointer: NonNull<T>,
+	ref_counter: NonNull<T::RefCounter>,
+}
+
+impl<T: IntrusivelyCountable> Clone for Weak<T>
+where
+	T::RefCounter: WeakRefCounter,
+{
+	/// Makes a clone of this [`Weak`], pointing to the same allocation.
+	///
+	/// This increases the weak reference count by 1.
+	fn clone(&self) -> Self {
+		unsafe { self.ref_counter.as_ref() }.increment_weak();
+		Self {
+			pointer: self.pointer,
+			ref_counter: self.ref_counter,
+		}
+	}
+}
+
+impl<T: IntrusivelyCountable> Debug for Weak<T>
+where
+	T::RefCounter: WeakRefCounter,
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.write_str("(Weak)")
+	}
+}
+
+impl<T: IntrusivelyCountable> Drop for Weak<T>
+where
+	T::RefCounter: WeakRefCounter,
+{
+	fn drop(&mut self) {
+		unsafe {
+			if self.ref_counter.as_ref().decrement_weak() {
+				dealloc(self.pointer.as_ptr().cast(), Layout::new::<T>())
+			}
+		}
+	}
+}
+
+impl<T: IntrusivelyCountable> Pointer for Weak<T>
+where
+	T::RefCounter: WeakRefCounter,
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		Pointer::fmt(&self.pointer, f)
+	}
+}
+
+impl<T: IntrusivelyCountable> Unpin for Weak<T> where T::RefCounter: WeakRefCounter {}
+
+impl<T: IntrusivelyCountable> Weak<T>
+where
+	T::RefCounter: WeakRefCounter,
+{
+	/// Attempts to create a new [`Pin<Rc<T>>`](`Rc`) to the payload.
+	///
+	/// Returns [`None`] iff the payload was already dropped or is currently mutably borrowed
+	/// through [`Rc::get_mut`] or [`Rc::make_mut`].
+	#[must_use]
+	pub fn upgrade(&self) -> Option<Pin<Rc<T>>> {
+		unsafe { self.ref_counter.as_ref() }
+			.upgrade()
+			.then(|| unsafe { Rc::pinned_from_raw(self.pointer) })
+	}
+
+	/// Checks whether two instances of [`Weak<T>`] point to the same allocation.
+	#[must_use]
+	pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+		this.pointer == other.pointer
+	}
+}
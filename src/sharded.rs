@@ -0,0 +1,30 @@
+//! Thread-to-slot assignment for [`ShardedTipToe`](`crate::ShardedTipToe`).
+
+use core::{
+	cell::Cell,
+	sync::atomic::{AtomicUsize, Ordering},
+};
+use std::thread_local;
+
+/// The index of the next thread that picks a slot.
+static NEXT: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+	static INDEX: Cell<Option<usize>> = const { Cell::new(None) };
+}
+
+/// The current thread's slot index below `slots`, which is assigned round-robin on first use.
+///
+/// This is [`None`] while the thread's locals are being torn down.
+pub(crate) fn index(slots: usize) -> Option<usize> {
+	INDEX
+		.try_with(|index| {
+			index.get().unwrap_or_else(|| {
+				let assigned = NEXT.fetch_add(1, Ordering::Relaxed);
+				index.set(Some(assigned));
+				assigned
+			})
+		})
+		.ok()
+		.map(|index| index % slots)
+}
@@ -1,52 +1,78 @@
+//! Thread-safe intrusively reference-counting pointers.
+
 use crate::{
-	ref_counter_api::{DecrementFollowup, RefCounterExt},
-	ExclusivePin, IntrusivelyCountable, ManagedClone,
+	counted::{Counted, HeaderSlice},
+	handle_exclusivity_violation,
+	ref_counter_api::{self, DecrementFollowup, RefCounterExt, WeakRefCounterExt},
+	AllocError, AtomicTipToe, ExclusivePin, ExclusivityViolation, IntrusivelyCountable,
+	ManagedClone, RefCounterFor, WeakRefCounter,
 };
 use alloc::{
+	alloc::handle_alloc_error,
 	borrow::{Cow, ToOwned},
 	boxed::Box,
+	vec::Vec,
 };
+use allocator_api2::alloc::{Allocator, Global};
 use core::{
+	alloc::Layout,
 	any::{Any, TypeId},
 	borrow::Borrow,
+	convert::Infallible,
 	fmt::{self, Debug, Display, Formatter, Pointer},
 	hash::{Hash, Hasher},
-	mem::{self, ManuallyDrop},
-	ops::Deref,
+	hint,
+	iter::{self, FromIterator, FusedIterator},
+	marker::PhantomData,
+	mem::{self, ManuallyDrop, MaybeUninit},
+	ops::{Deref, DerefMut},
 	pin::Pin,
-	ptr::NonNull,
+	ptr::{self, NonNull},
+	sync::atomic::{AtomicPtr, Ordering},
+	task::{RawWaker, RawWakerVTable, Waker},
 };
-use tap::{Pipe, Tap};
+use tap::{Pipe, Tap, TapFallible};
 
 // spell-checker:ignore eference ounted
 /// An **a**synchronously **r**eference-**c**ounted smart pointer (copy-on-write single-item container).
 ///
 /// Unlike with [`alloc::sync::Arc`], the reference-count must be embedded in the payload instance itself.
-#[repr(transparent)]
-pub struct Arc<T: ?Sized + IntrusivelyCountable> {
+///
+/// [`Arc`] is only [`Send`] and [`Sync`] for [`Sync`] payloads,
+/// which usually means they should embed an [`AtomicTipToe`](`crate::AtomicTipToe`) or [`AtomicTipToes`](`crate::AtomicTipToes`).
+///
+/// The payload is allocated through `A`, which defaults to the [`Global`] allocator.
+/// See [`Arc::new_in`] and [`Arc::pin_in`] to use another [`Allocator`].
+#[repr(C)]
+pub struct Arc<T: ?Sized + IntrusivelyCountable, A: Allocator = Global> {
 	pointer: NonNull<T>,
+	/// Zero-sized for [`Global`], so that [`Arc<T>`] can be reinterpreted from `&T`.
+	allocator: A,
 }
 
-impl<T: ?Sized + IntrusivelyCountable> AsRef<T> for Arc<T> {
+impl<T: ?Sized + IntrusivelyCountable, A: Allocator> AsRef<T> for Arc<T, A> {
 	fn as_ref(&self) -> &T {
 		self
 	}
 }
 
-impl<T: ?Sized + IntrusivelyCountable> Borrow<T> for Arc<T> {
+impl<T: ?Sized + IntrusivelyCountable, A: Allocator> Borrow<T> for Arc<T, A> {
 	fn borrow(&self) -> &T {
 		self
 	}
 }
 
-impl<T: ?Sized + IntrusivelyCountable> Clone for Arc<T> {
+impl<T: ?Sized + IntrusivelyCountable, A: Allocator + Clone> Clone for Arc<T, A> {
 	/// Makes a clone of this [`Arc`], pointing to the same instance.
 	///
 	/// This increases the strong reference count by 1.
 	fn clone(&self) -> Self {
 		self.ref_counter().increment();
+		#[cfg(feature = "std")]
+		ref_counter_api::drop_pending::<T::RefCounter>();
 		Self {
 			pointer: self.pointer,
+			allocator: self.allocator.clone(),
 		}
 	}
 
@@ -57,7 +83,22 @@ impl<T: ?Sized + IntrusivelyCountable> Clone for Arc<T> {
 	}
 }
 
-impl<T: ?Sized + IntrusivelyCountable> Debug for Arc<T>
+#[cfg(feature = "nightly")]
+impl<T: ?Sized + IntrusivelyCountable, U: ?Sized + IntrusivelyCountable, A: Allocator>
+	core::ops::CoerceUnsized<Arc<U, A>> for Arc<T, A>
+where
+	T: core::marker::Unsize<U>,
+	U: IntrusivelyCountable<RefCounter = T::RefCounter>,
+{
+}
+
+#[cfg(feature = "nightly")]
+unsafe impl<T: ?Sized + IntrusivelyCountable, A: Allocator> core::pin::PinCoerceUnsized
+	for Arc<T, A>
+{
+}
+
+impl<T: ?Sized + IntrusivelyCountable, A: Allocator> Debug for Arc<T, A>
 where
 	T: Debug,
 {
@@ -66,6 +107,7 @@ where
 	}
 }
 
+#[allow(clippy::needless_maybe_sized)]
 impl<T: ?Sized + IntrusivelyCountable> Default for Arc<T>
 where
 	T: Default,
@@ -75,7 +117,7 @@ where
 	}
 }
 
-impl<T: ?Sized + IntrusivelyCountable> Deref for Arc<T> {
+impl<T: ?Sized + IntrusivelyCountable, A: Allocator> Deref for Arc<T, A> {
 	type Target = T;
 
 	fn deref(&self) -> &Self::Target {
@@ -83,7 +125,7 @@ impl<T: ?Sized + IntrusivelyCountable> Deref for Arc<T> {
 	}
 }
 
-impl<T: ?Sized + IntrusivelyCountable> Display for Arc<T>
+impl<T: ?Sized + IntrusivelyCountable, A: Allocator> Display for Arc<T, A>
 where
 	T: Display,
 {
@@ -92,24 +134,31 @@ where
 	}
 }
 
-impl<T: ?Sized + IntrusivelyCountable> Drop for Arc<T> {
+impl<T: ?Sized + IntrusivelyCountable, A: Allocator> Drop for Arc<T, A> {
 	fn drop(&mut self) {
-		unsafe {
-			match self.ref_counter().decrement() {
-				DecrementFollowup::LeakIt => (),
-				DecrementFollowup::DropOrMoveIt => drop(Box::from_raw(self.pointer.as_ptr())),
-			}
-		}
+		unsafe { Self::drop_n(self.pointer, 1, &self.allocator) }
 	}
 }
 
-impl<T: ?Sized + IntrusivelyCountable> Eq for Arc<T> where T: Eq {}
+impl<T: ?Sized + IntrusivelyCountable, A: Allocator> Eq for Arc<T, A> where T: Eq {}
 
 impl<T: ?Sized + IntrusivelyCountable> From<Box<T>> for Arc<T> {
 	/// Converts a [`Box`] into an [`Arc`] without reallocating.
 	fn from(box_: Box<T>) -> Self {
 		box_.ref_counter().increment();
 		unsafe { Self::from_raw(NonNull::new_unchecked(Box::leak(box_))) }
+			.tap(|this| Self::bias(this))
+	}
+}
+
+impl<T: ?Sized + IntrusivelyCountable, A: Allocator> From<allocator_api2::boxed::Box<T, A>>
+	for Arc<T, A>
+{
+	/// Converts an [`allocator_api2::boxed::Box`] into an [`Arc`] without reallocating.
+	fn from(box_: allocator_api2::boxed::Box<T, A>) -> Self {
+		box_.ref_counter().increment();
+		let (pointer, allocator) = allocator_api2::boxed::Box::into_raw_with_allocator(box_);
+		unsafe { Self::from_raw_in(NonNull::new_unchecked(pointer), allocator) }
 	}
 }
 
@@ -128,6 +177,25 @@ where
 	}
 }
 
+impl<T, C: RefCounterFor<[T]> + Default> From<Vec<T>> for Arc<Counted<[T], C>> {
+	/// Moves the items of `vec` into a new allocation.
+	fn from(vec: Vec<T>) -> Self {
+		Box::<Counted<[T], C>>::from(vec).into()
+	}
+}
+
+impl<T: Clone, C: RefCounterFor<[T]> + Default> From<&[T]> for Arc<Counted<[T], C>> {
+	fn from(slice: &[T]) -> Self {
+		Box::<Counted<[T], C>>::from(slice).into()
+	}
+}
+
+impl<C: RefCounterFor<str> + Default> From<&str> for Arc<Counted<str, C>> {
+	fn from(str: &str) -> Self {
+		Box::<Counted<str, C>>::from(str).into()
+	}
+}
+
 impl<T: Sized + IntrusivelyCountable> From<T> for Arc<T> {
 	fn from(value: T) -> Self {
 		Self::new(value)
@@ -140,25 +208,33 @@ impl<T: Sized + IntrusivelyCountable> From<T> for Pin<Arc<T>> {
 	}
 }
 
-impl<T: ?Sized + IntrusivelyCountable> From<Pin<Arc<T>>> for Arc<T>
+impl<T: ?Sized + IntrusivelyCountable, A: Allocator> From<Pin<Arc<T, A>>> for Arc<T, A>
 where
 	T: Unpin,
 {
-	fn from(pinned: Pin<Arc<T>>) -> Self {
+	fn from(pinned: Pin<Arc<T, A>>) -> Self {
 		unsafe { Pin::into_inner_unchecked(pinned) }
 	}
 }
 
-impl<T: ?Sized + IntrusivelyCountable> From<Arc<T>> for Pin<Arc<T>>
+impl<T: ?Sized + IntrusivelyCountable, A: Allocator> From<Arc<T, A>> for Pin<Arc<T, A>>
 where
 	T: Unpin,
 {
-	fn from(unpinned: Arc<T>) -> Self {
+	fn from(unpinned: Arc<T, A>) -> Self {
 		unsafe { Pin::new_unchecked(unpinned) }
 	}
 }
 
-impl<T: ?Sized + IntrusivelyCountable> Hash for Arc<T>
+impl<T, C: RefCounterFor<[T]> + Default> FromIterator<T> for Arc<Counted<[T], C>> {
+	/// Allocates only once iff `iter`'s [size hint](`Iterator::size_hint`) is exact and accurate,
+	/// and otherwise collects the items into a [`Vec`] first.
+	fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+		iter.into_iter().collect::<Box<Counted<[T], C>>>().into()
+	}
+}
+
+impl<T: ?Sized + IntrusivelyCountable, A: Allocator> Hash for Arc<T, A>
 where
 	T: Hash,
 {
@@ -167,7 +243,7 @@ where
 	}
 }
 
-impl<T: ?Sized + IntrusivelyCountable> Ord for Arc<T>
+impl<T: ?Sized + IntrusivelyCountable, A: Allocator> Ord for Arc<T, A>
 where
 	T: Ord,
 {
@@ -176,35 +252,53 @@ where
 	}
 }
 
-impl<T: ?Sized + IntrusivelyCountable, O: ?Sized + IntrusivelyCountable> PartialEq<Arc<O>>
-	for Arc<T>
+impl<
+		T: ?Sized + IntrusivelyCountable,
+		A: Allocator,
+		O: ?Sized + IntrusivelyCountable,
+		B: Allocator,
+	> PartialEq<Arc<O, B>> for Arc<T, A>
 where
 	T: PartialEq<O>,
 {
-	fn eq(&self, other: &Arc<O>) -> bool {
+	fn eq(&self, other: &Arc<O, B>) -> bool {
 		(**self) == (**other)
 	}
 }
 
-impl<T: ?Sized + IntrusivelyCountable, O: ?Sized + IntrusivelyCountable> PartialOrd<Arc<O>>
-	for Arc<T>
+impl<
+		T: ?Sized + IntrusivelyCountable,
+		A: Allocator,
+		O: ?Sized + IntrusivelyCountable,
+		B: Allocator,
+	> PartialOrd<Arc<O, B>> for Arc<T, A>
 where
 	T: PartialOrd<O>,
 {
-	fn partial_cmp(&self, other: &Arc<O>) -> Option<core::cmp::Ordering> {
+	fn partial_cmp(&self, other: &Arc<O, B>) -> Option<core::cmp::Ordering> {
 		(**self).partial_cmp(other)
 	}
 }
 
-impl<T: ?Sized + IntrusivelyCountable> Pointer for Arc<T> {
+impl<T: ?Sized + IntrusivelyCountable, A: Allocator> Pointer for Arc<T, A> {
 	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
 		Pointer::fmt(&self.pointer, f)
 	}
 }
 
-unsafe impl<T: ?Sized + IntrusivelyCountable> Send for Arc<T> where T: Sync + Send {}
-unsafe impl<T: ?Sized + IntrusivelyCountable> Sync for Arc<T> where T: Sync + Send {}
-impl<T: ?Sized + IntrusivelyCountable> Unpin for Arc<T> {}
+unsafe impl<T: ?Sized + IntrusivelyCountable, A: Allocator> Send for Arc<T, A>
+where
+	T: Sync + Send,
+	A: Send,
+{
+}
+unsafe impl<T: ?Sized + IntrusivelyCountable, A: Allocator> Sync for Arc<T, A>
+where
+	T: Sync + Send,
+	A: Sync,
+{
+}
+impl<T: ?Sized + IntrusivelyCountable, A: Allocator> Unpin for Arc<T, A> {}
 
 impl<T: ?Sized + IntrusivelyCountable> Arc<T> {
 	/// Creates a new instance of [`Arc<_>`] by moving `value` into a new heap allocation.
@@ -218,9 +312,7 @@ impl<T: ?Sized + IntrusivelyCountable> Arc<T> {
 	where
 		T: Sized,
 	{
-		value.ref_counter().increment();
-		let instance = Box::leak(Box::new(value));
-		unsafe { Self::from_raw(NonNull::new_unchecked(instance)) }
+		Self::new_in(value, Global).tap(|this| Self::bias(this))
 	}
 
 	/// Creates a new instance of [`Pin<Arc<_>>`](`Arc`) by moving `value` into a new heap allocation.
@@ -234,42 +326,82 @@ impl<T: ?Sized + IntrusivelyCountable> Arc<T> {
 	where
 		T: Sized,
 	{
-		value.ref_counter().increment();
-		let instance = Box::leak(Box::new(value));
-		unsafe { Pin::new_unchecked(Self::from_raw(NonNull::new_unchecked(instance))) }
+		Self::pin_in(value, Global).tap(|this| Self::bias(this))
 	}
 
+	/// Creates a new instance of [`Arc<_>`] by moving `value` into a new heap allocation, without aborting if that fails.
+	///
+	/// This increases the intrusive reference-count by 1.
+	///
+	/// Calling this method with an instance with non-zero reference-count is safe,
+	/// but likely to lead to memory leaks (or the process being aborted, if the recorded count is very high).
+	///
 	/// # Errors
 	///
-	/// Iff this [`Arc`] is not an exclusive handle.
-	pub fn try_unpin(this: Pin<Self>) -> Result<T, Pin<Self>>
+	/// Iff the allocation fails, in which case `value` is handed back through the [`AllocError`].
+	pub fn try_new(value: T) -> Result<Self, AllocError<T>>
 	where
-		T: Sized + Unpin,
+		T: Sized,
 	{
-		Pin::into_inner(this)
-			.pipe(Self::try_unwrap)
-			.map_err(Pin::new)
+		Self::try_new_in(value, Global).tap_ok(|this| Self::bias(this))
 	}
 
+	/// Creates a new instance of [`Pin<Arc<_>>`](`Arc`) by moving `value` into a new heap allocation, without aborting if that fails.
+	///
+	/// This increases the intrusive reference-count by 1.
+	///
+	/// Calling this method with an instance with non-zero reference-count is safe,
+	/// but likely to lead to memory leaks (or the process being aborted, if the recorded count is very high).
+	///
 	/// # Errors
 	///
-	/// Iff this [`Arc`] is not an exclusive handle.
-	pub fn try_unwrap(this: Self) -> Result<T, Self>
+	/// Iff the allocation fails, in which case `value` is handed back through the [`AllocError`].
+	pub fn try_pin(value: T) -> Result<Pin<Self>, AllocError<T>>
 	where
 		T: Sized,
 	{
-		match unsafe { this.ref_counter().acquire() } {
-			None => Err(this),
-			Some(exclusivity) => unsafe {
-				drop(exclusivity); // We still have exclusivity until we relinquish control. However, we do want to manipulate the reference count.
-				Ok(ManuallyDrop::take(
-					mem::transmute::<Self, Arc<ManuallyDrop<T>>>(this)
-						.pointer
-						.as_mut(),
-				)
-				.tap_mut(|unwrapped| unwrapped.ref_counter().decrement_relaxed().pipe(drop)))
-			},
-		}
+		Self::try_pin_in(value, Global).tap_ok(|this| Self::bias(this))
+	}
+
+	/// Creates a new instance of [`Pin<Arc<_>>`](`Arc`) by initialising its payload in place in a new heap allocation.
+	///
+	/// See [`Arc::try_pin_init_in`] for details.
+	///
+	/// # Safety
+	///
+	/// `init` must fully initialise the payload.
+	#[must_use]
+	pub unsafe fn pin_init(init: impl FnOnce(Pin<&mut MaybeUninit<T>>)) -> Pin<Self>
+	where
+		T: Sized,
+	{
+		Self::pin_init_in(init, Global).tap(|this| Self::bias(this))
+	}
+
+	/// Creates a new instance of [`Pin<Arc<_>>`](`Arc`) by fallibly initialising its payload in place in a new heap allocation.
+	///
+	/// See [`Arc::try_pin_init_in`] for details.
+	///
+	/// # Errors
+	///
+	/// Iff `init` fails, its error is returned and the allocation is freed again.
+	///
+	/// # Safety
+	///
+	/// `init` must fully initialise the payload iff it returns [`Ok`].
+	pub unsafe fn try_pin_init<E>(
+		init: impl FnOnce(Pin<&mut MaybeUninit<T>>) -> Result<(), E>,
+	) -> Result<Pin<Self>, E>
+	where
+		T: Sized,
+	{
+		Self::try_pin_init_in(init, Global).tap_ok(|this| Self::bias(this))
+	}
+
+	/// Lets the current thread count references to the payload of the fresh handle `this` non-atomically,
+	/// if its reference counter supports that.
+	fn bias(this: &T) {
+		unsafe { ref_counter_api::bias(this.ref_counter()) }
 	}
 
 	/// Constructs an [`Arc`] instance from a compatible value pointer.
@@ -291,12 +423,7 @@ impl<T: ?Sized + IntrusivelyCountable> Arc<T> {
 	/// The data `raw_value` points to may be in use only by [`Arc`].
 	#[must_use = "Implicitly dropping this handle is likely a mistake."]
 	pub unsafe fn from_raw(raw_value: NonNull<T>) -> Self {
-		debug_assert_ne!(
-			raw_value.as_ptr().cast::<()>() as usize,
-			0,
-			"Called `tiptoe::Arc::from_raw` with null pointer."
-		);
-		Self { pointer: raw_value }
+		Self::from_raw_in(raw_value, Global)
 	}
 
 	/// Constructs a [pinned](`core::pin`) [`Arc`] instance from a compatible value pointer.
@@ -318,12 +445,7 @@ impl<T: ?Sized + IntrusivelyCountable> Arc<T> {
 	/// The data `raw_value` points to may be in use only by [`Arc`].
 	#[must_use = "Implicitly dropping this handle is likely a mistake."]
 	pub unsafe fn pinned_from_raw(raw_value: NonNull<T>) -> Pin<Self> {
-		debug_assert_ne!(
-			raw_value.as_ptr().cast::<()>() as usize,
-			0,
-			"Called `tiptoe::Arc::from_raw` with null pointer."
-		);
-		Self { pointer: raw_value }.pipe(|this| Pin::new_unchecked(this))
+		Self::pinned_from_raw_in(raw_value, Global)
 	}
 
 	/// Unsafely borrows a shared reference to an [`Arc`]-managed instance as [`Arc`].
@@ -333,7 +455,12 @@ impl<T: ?Sized + IntrusivelyCountable> Arc<T> {
 	/// # Safety
 	///
 	/// `inner` must be a reference to a reference to an instance managed by [`Arc`].
+	///
+	/// # See also
+	///
+	/// [`ArcBorrow::from_inner_ref`], which doesn't require a double reference.
 	#[must_use]
+	#[allow(clippy::ref_as_ptr)]
 	pub unsafe fn borrow_from_inner_ref<'a>(inner: &'a &'a T) -> &'a Self {
 		&*(inner as *const &T).cast::<Self>()
 	}
@@ -345,7 +472,12 @@ impl<T: ?Sized + IntrusivelyCountable> Arc<T> {
 	/// # Safety
 	///
 	/// `inner` must be a reference to a reference to an instance managed by [`Pin<Arc>`].
+	///
+	/// # See also
+	///
+	/// [`ArcBorrow::pinned_from_inner_ref`], which doesn't require a double reference.
 	#[must_use]
+	#[allow(clippy::ref_as_ptr)]
 	pub unsafe fn borrow_pin_from_inner_ref<'a>(inner: &'a &'a T) -> &'a Pin<Self> {
 		&*(inner as *const &T).cast::<Pin<Self>>()
 	}
@@ -355,9 +487,7 @@ impl<T: ?Sized + IntrusivelyCountable> Arc<T> {
 	/// This does not decrease the reference-count.
 	#[must_use = "Ignoring this pointer will usually lead to the underlying payload instance leaking."]
 	pub fn leak(this: Self) -> NonNull<T> {
-		let pointer = this.pointer;
-		mem::forget(this);
-		pointer
+		Self::into_raw_parts(this).0
 	}
 
 	/// Unwraps the payload pointer contained in the current instance.
@@ -369,91 +499,2070 @@ impl<T: ?Sized + IntrusivelyCountable> Arc<T> {
 	/// Keep in mind that the pinning invariants, including the drop guarantee, must still be upheld.
 	#[must_use = "Ignoring this pointer will usually lead to the underlying payload instance leaking."]
 	pub fn leak_pinned(this: Pin<Self>) -> NonNull<T> {
-		let this = unsafe { Pin::into_inner_unchecked(this) };
-		let pointer = this.pointer;
-		mem::forget(this);
-		pointer
+		Self::into_raw_parts(unsafe { Pin::into_inner_unchecked(this) }).0
+	}
+
+	/// Converts this [`Arc`] into a [`Box`] without reallocating, iff it is the only handle to its payload.
+	///
+	/// The intrusive reference-count is reset to 0, as [`Arc::from`] a [`Box`] increments it again.
+	///
+	/// # Errors
+	///
+	/// Iff this [`Arc`] is not the only (strong or weak) handle to its payload.
+	pub fn try_into_box(this: Self) -> Result<Box<T>, Self> {
+		if unsafe { this.ref_counter().try_decrement_unique() } {
+			Ok(unsafe { Box::from_raw(Self::leak(this).as_ptr()) })
+		} else {
+			Err(this)
+		}
+	}
+
+	/// Converts this [`Pin<Arc>`] into a [`Pin<Box>`] without reallocating, iff it is the only handle to its payload.
+	///
+	/// The intrusive reference-count is reset to 0, as [`Arc::from`] a [`Box`] increments it again.
+	///
+	/// # Errors
+	///
+	/// Iff this [`Arc`] is not the only (strong or weak) handle to its payload.
+	pub fn try_into_pin_box(this: Pin<Self>) -> Result<Pin<Box<T>>, Pin<Self>> {
+		unsafe {
+			Self::try_into_box(Pin::into_inner_unchecked(this))
+				.map(|box_| Pin::new_unchecked(box_))
+				.map_err(|this| Pin::new_unchecked(this))
+		}
 	}
 
-	/// Checks whether two instances of [`Arc<T>`] point to the same instance.
+	/// Converts this [`Pin<Arc>`] into a [`Waker`] without an extra allocation.
+	///
+	/// Cloning and dropping the [`Waker`] increments and decrements the intrusive reference-count directly,
+	/// and waking it calls [`IntrusiveWake::wake`] or [`IntrusiveWake::wake_by_ref`].
 	#[must_use]
-	pub fn ptr_eq(this: &Self, other: &Self) -> bool {
-		this.pointer == other.pointer
+	pub fn into_waker(this: Pin<Self>) -> Waker
+	where
+		T: Sized + IntrusiveWake + Send + Sync + 'static,
+	{
+		let data = Self::leak_pinned(this).as_ptr().cast::<()>();
+		unsafe { Waker::from_raw(raw_waker::<T>(data)) }
 	}
 
-	/// Ensures the payload is exclusively pointed to by this [`Arc<T>`], cloning it if necessary,
-	/// and gives access to a [`Pin<&mut T>`] that safely can *not* be used to clone the [`Arc<T>`].
-	pub fn make_mut(this: &mut Pin<Self>) -> ExclusivePin<T>
+	/// Gives up this handle's strong reference later, during [`batch::flush`](`crate::batch::flush`),
+	/// along with other batched handles to the same instance.
+	///
+	/// This may flush the current thread's buffer right away, if it is full.
+	#[cfg(feature = "std")]
+	pub fn drop_batched(this: Self)
 	where
-		T: Sized + ManagedClone,
+		T: Sized + 'static,
 	{
-		let exclusivity = unsafe { this.ref_counter().acquire() }.unwrap_or_else(|| {
-			*this = unsafe {
-				// Safety:
-				// No effective encapsulation change happens.
-				// `Self::pin` does call `IntrusivelyCountable::ref_counter`, but this is legal as that method is not allowed to have effects.
-				(&**this).managed_clone().pipe(Self::pin)
-			};
+		crate::batch::push(this)
+	}
 
-			// This could be done faster, but whether that's significant is up to benchmarking it.
-			unsafe { this.ref_counter().acquire() }.expect("unreachable")
-		});
+	/// Gives up this [`Pin<Arc>`]'s strong reference later, during [`batch::flush`](`crate::batch::flush`),
+	/// along with other batched handles to the same instance.
+	///
+	/// See [`Arc::drop_batched`].
+	#[cfg(feature = "std")]
+	pub fn drop_batched_pinned(this: Pin<Self>)
+	where
+		T: Sized + 'static,
+	{
+		// The payload isn't moved out.
+		Self::drop_batched(unsafe { Pin::into_inner_unchecked(this) })
+	}
+}
 
-		ExclusivePin::new(exclusivity, unsafe {
-			Pin::new_unchecked(
-				(*(this as *mut Pin<Self>).cast::<Arc<T>>())
-					.pointer
-					.as_mut(),
-			)
-		})
+impl<T: ?Sized + IntrusivelyCountable, A: Allocator> Arc<T, A> {
+	/// Creates a new instance of [`Arc<_, A>`](`Arc`) by moving `value` into a new allocation from `allocator`.
+	///
+	/// This increases the intrusive reference-count by 1.
+	///
+	/// Calling this method with an instance with non-zero reference-count is safe,
+	/// but likely to lead to memory leaks (or the process being aborted, if the recorded count is very high).
+	#[must_use]
+	pub fn new_in(value: T, allocator: A) -> Self
+	where
+		T: Sized,
+	{
+		Self::try_new_in(value, allocator)
+			.unwrap_or_else(|_| handle_alloc_error(Layout::new::<T>()))
 	}
 
-	/// Checks whether the payload is exclusively pointed to by this [`Arc<T>`] and, if this is the case,
-	/// gives access to a [`Pin<&mut T>`] that safely can *not* be used to clone the [`Arc<T>`].
+	/// Creates a new instance of [`Pin<Arc<_, A>>`](`Arc`) by moving `value` into a new allocation from `allocator`.
+	///
+	/// This increases the intrusive reference-count by 1.
+	///
+	/// Calling this method with an instance with non-zero reference-count is safe,
+	/// but likely to lead to memory leaks (or the process being aborted, if the recorded count is very high).
 	#[must_use]
-	pub fn get_mut(this: &mut Pin<Self>) -> Option<ExclusivePin<T>> {
-		unsafe { this.ref_counter().acquire() }.map(|exclusivity| {
-			ExclusivePin::new(exclusivity, unsafe {
-				Pin::new_unchecked(
-					(*(this as *mut Pin<Self>).cast::<Arc<T>>())
-						.pointer
-						.as_mut(),
-				)
-			})
-		})
+	pub fn pin_in(value: T, allocator: A) -> Pin<Self>
+	where
+		T: Sized,
+	{
+		unsafe { Pin::new_unchecked(Self::new_in(value, allocator)) }
 	}
 
-	/// Attempts to cast this [`Arc`] into once of concrete type `U`.
+	/// Creates a new instance of [`Arc<_, A>`](`Arc`) by moving `value` into a new allocation from `allocator`, without aborting if that fails.
+	///
+	/// This increases the intrusive reference-count by 1.
+	///
+	/// Calling this method with an instance with non-zero reference-count is safe,
+	/// but likely to lead to memory leaks (or the process being aborted, if the recorded count is very high).
 	///
 	/// # Errors
 	///
-	/// Iff the underlying instance isn't a `U`.
-	pub fn downcast<U>(this: Self) -> Result<Arc<U>, Self>
+	/// Iff the allocation fails, in which case `value` is handed back through the [`AllocError`].
+	pub fn try_new_in(value: T, allocator: A) -> Result<Self, AllocError<T>>
 	where
-		T: Any,
-		U: Any + IntrusivelyCountable,
+		T: Sized,
 	{
-		if Any::type_id(&*this) == TypeId::of::<U>() {
-			Ok(unsafe { Arc::from_raw(Arc::leak(this).cast()) })
-		} else {
-			Err(this)
+		match allocator_api2::boxed::Box::<T, A>::try_new_uninit_in(allocator) {
+			Ok(mut uninit) => unsafe {
+				uninit.as_mut_ptr().write(value);
+				Ok(uninit.assume_init().into())
+			},
+			Err(_) => Err(AllocError { value }),
 		}
 	}
 
-	/// Attempts to cast this [`Arc`] into once of concrete type `U`.
+	/// Creates a new instance of [`Pin<Arc<_, A>>`](`Arc`) by moving `value` into a new allocation from `allocator`, without aborting if that fails.
+	///
+	/// This increases the intrusive reference-count by 1.
+	///
+	/// Calling this method with an instance with non-zero reference-count is safe,
+	/// but likely to lead to memory leaks (or the process being aborted, if the recorded count is very high).
 	///
 	/// # Errors
 	///
-	/// Iff the underlying instance isn't a `U`.
-	pub fn downcast_pinned<U>(this: Pin<Self>) -> Result<Pin<Arc<U>>, Pin<Self>>
+	/// Iff the allocation fails, in which case `value` is handed back through the [`AllocError`].
+	pub fn try_pin_in(value: T, allocator: A) -> Result<Pin<Self>, AllocError<T>>
 	where
-		T: Any,
-		U: Any + IntrusivelyCountable,
+		T: Sized,
 	{
-		if Any::type_id(&*this) == TypeId::of::<U>() {
-			Ok(unsafe { Arc::pinned_from_raw(Arc::leak_pinned(this).cast()) })
-		} else {
-			Err(this)
+		Self::try_new_in(value, allocator).map(|this| unsafe { Pin::new_unchecked(this) })
+	}
+
+	/// Creates a new instance of [`Pin<Arc<_, A>>`](`Arc`) by initialising its payload in place in a new allocation from `allocator`.
+	///
+	/// See [`Arc::try_pin_init_in`] for details.
+	///
+	/// # Safety
+	///
+	/// `init` must fully initialise the payload.
+	#[must_use]
+	pub unsafe fn pin_init_in(
+		init: impl FnOnce(Pin<&mut MaybeUninit<T>>),
+		allocator: A,
+	) -> Pin<Self>
+	where
+		T: Sized,
+	{
+		match Self::try_pin_init_in(
+			|uninit| {
+				init(uninit);
+				Ok::<_, Infallible>(())
+			},
+			allocator,
+		) {
+			Ok(this) => this,
+			Err(infallible) => match infallible {},
+		}
+	}
+
+	/// Creates a new instance of [`Pin<Arc<_, A>>`](`Arc`) by fallibly initialising its payload in place in a new allocation from `allocator`.
+	///
+	/// Unlike with [`Arc::pin_in`], the payload is never moved,
+	/// so large or self-referential values don't have to be built on the stack first.
+	///
+	/// The memory passed to `init` is already pinned.
+	/// The intrusive reference-count is increased by 1 only once `init` returns [`Ok`].
+	///
+	/// # Errors
+	///
+	/// Iff `init` fails, its error is returned and the allocation is freed again.
+	/// (The allocation is also freed if `init` panics.)
+	///
+	/// # Safety
+	///
+	/// `init` must fully initialise the payload iff it returns [`Ok`].
+	///
+	/// Initialising the payload with a non-zero reference-count is sound,
+	/// but likely to lead to memory leaks (or the process being aborted, if the recorded count is very high).
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use allocator_api2::alloc::Global;
+	/// use core::{mem::MaybeUninit, pin::Pin, ptr};
+	/// use tiptoe::{Arc, AtomicTipToe, IntrusivelyCountable};
+	///
+	/// struct Large {
+	///     counter: AtomicTipToe,
+	///     data: [u8; 4096],
+	/// }
+	///
+	/// unsafe impl IntrusivelyCountable for Large {
+	///     type RefCounter = AtomicTipToe;
+	///
+	///     fn ref_counter(&self) -> &Self::RefCounter {
+	///         &self.counter
+	///     }
+	/// }
+	///
+	/// let large = unsafe {
+	///     Arc::try_pin_init_in(
+	///         |uninit: Pin<&mut MaybeUninit<Large>>| {
+	///             let large = uninit.get_unchecked_mut().as_mut_ptr();
+	///             ptr::addr_of_mut!((*large).counter).write(AtomicTipToe::new());
+	///             ptr::addr_of_mut!((*large).data).write_bytes(1, 1);
+	///             Ok::<_, ()>(())
+	///         },
+	///         Global,
+	///     )
+	/// }
+	/// .unwrap();
+	///
+	/// assert_eq!(large.data[4095], 1);
+	/// ```
+	pub unsafe fn try_pin_init_in<E>(
+		init: impl FnOnce(Pin<&mut MaybeUninit<T>>) -> Result<(), E>,
+		allocator: A,
+	) -> Result<Pin<Self>, E>
+	where
+		T: Sized,
+	{
+		// Dropping this on error or panic frees the allocation without dropping the payload.
+		let mut uninit = allocator_api2::boxed::Box::new_uninit_in(allocator);
+		init(Pin::new_unchecked(&mut *uninit))?;
+		Ok(Pin::new_unchecked(uninit.assume_init().into()))
+	}
+
+	/// # Errors
+	///
+	/// Iff this [`Arc`] is not an exclusive handle.
+	pub fn try_unpin(this: Pin<Self>) -> Result<T, Pin<Self>>
+	where
+		T: Sized + Unpin,
+	{
+		Pin::into_inner(this)
+			.pipe(Self::try_unwrap)
+			.map_err(Pin::new)
+	}
+
+	/// # Errors
+	///
+	/// Iff this [`Arc`] is not an exclusive handle.
+	pub fn try_unwrap(this: Self) -> Result<T, Self>
+	where
+		T: Sized,
+	{
+		if !unsafe { this.ref_counter().try_decrement_last() } {
+			return Err(this);
+		}
+
+		// The reference count is now zero, so (unlike with an `Exclusivity`) no handle can be cloned or upgraded anymore.
+		let (pointer, allocator) = Self::into_raw_parts(this);
+		Ok(unsafe { Self::move_out(pointer, allocator) })
+	}
+
+	/// Unwraps the payload iff this is the last [`Arc`] pointing to it, and otherwise just drops this handle.
+	///
+	/// Unlike with [`Arc::try_unwrap`] followed by a drop on failure,
+	/// exactly one caller receives the payload even if several race to unwrap it,
+	/// as with [`alloc::sync::Arc::into_inner`].
+	#[must_use]
+	pub fn into_inner(this: Self) -> Option<T>
+	where
+		T: Sized,
+	{
+		let (pointer, allocator) = Self::into_raw_parts(this);
+		match unsafe { Self::decrement_or_hand_off(pointer, 1) } {
+			None | Some(DecrementFollowup::LeakIt) => None,
+			Some(DecrementFollowup::DropOrMoveIt) => {
+				Some(unsafe { Self::move_out(pointer, allocator) })
+			}
 		}
 	}
+
+	/// Unwraps the payload iff this is the last [`Arc`] pointing to it, and otherwise clones it.
+	#[must_use]
+	pub fn unwrap_or_clone(this: Self) -> T
+	where
+		T: Sized + ManagedClone,
+	{
+		Self::try_unwrap(this).unwrap_or_else(|this| unsafe {
+			// Safety:
+			// The payload of an unpinned `Arc` may be moved out of it, so a bare clone is equally encapsulated.
+			T::managed_clone(&this)
+		})
+	}
+
+	/// Gives up `n` strong references `pointer` at once, unless they have to be handed to the owner thread of a
+	/// [`BiasedTipToe`](`crate::BiasedTipToe`) instead, in which case [`None`] is returned.
+	///
+	/// # Safety
+	///
+	/// See [`RefCounterExt::decrement`], for `n` references.
+	#[cfg_attr(not(feature = "std"), allow(clippy::unnecessary_wraps))]
+	unsafe fn decrement_or_hand_off(pointer: NonNull<T>, n: usize) -> Option<DecrementFollowup> {
+		#[cfg(feature = "std")]
+		{
+			let mut n = n;
+			loop {
+				match ref_counter_api::decrement_or_defer(pointer.as_ref().ref_counter(), n) {
+					Ok(followup) => {
+						// After `LeakIt`, this may drop the payload, but `pointer` isn't used anymore then.
+						ref_counter_api::drop_pending::<T::RefCounter>();
+						return Some(followup);
+					}
+					// Only `Arc`s with the global allocator are biased.
+					Err(deferral) => {
+						if deferral.defer(pointer) {
+							n -= 1;
+							if n == 0 {
+								return None;
+							}
+						}
+					}
+				}
+			}
+		}
+
+		#[cfg(not(feature = "std"))]
+		Some(ref_counter_api::decrement_n(
+			pointer.as_ref().ref_counter(),
+			n,
+		))
+	}
+
+	/// Gives up `n` strong references `pointer` at once and drops the payload if they were the last ones.
+	///
+	/// # Safety
+	///
+	/// `pointer` must hold `n` strong references, each of which could be reconstructed with [`Arc::from_raw_in`] with `allocator`.
+	pub(crate) unsafe fn drop_n(pointer: NonNull<T>, n: usize, allocator: &A) {
+		match Self::decrement_or_hand_off(pointer, n) {
+			None | Some(DecrementFollowup::LeakIt) => (),
+			Some(DecrementFollowup::DropOrMoveIt) => {
+				let layout = Layout::for_value(pointer.as_ref());
+				let ref_counter: *const T::RefCounter = pointer.as_ref().ref_counter();
+				ptr::drop_in_place(pointer.as_ptr());
+				if (*ref_counter).release_allocation() {
+					allocator.deallocate(pointer.cast(), layout)
+				}
+			}
+		}
+	}
+
+	/// Moves the payload out of its allocation, which is freed unless there are weak handles to it,
+	/// and resets its reference counter.
+	///
+	/// # Safety
+	///
+	/// Must be called exactly once, after following up on [`DecrementFollowup::DropOrMoveIt`].
+	unsafe fn move_out(pointer: NonNull<T>, allocator: A) -> T
+	where
+		T: Sized,
+	{
+		let layout = Layout::for_value(pointer.as_ref());
+		let ref_counter: *const T::RefCounter = pointer.as_ref().ref_counter();
+		let value = pointer.as_ptr().read();
+		if (*ref_counter).release_allocation() {
+			allocator.deallocate(pointer.cast(), layout)
+		}
+		value.tap_mut(|unwrapped| unwrapped.ref_counter().reset())
+	}
+
+	/// Constructs an [`Arc`] instance from a compatible value pointer and its allocator.
+	///
+	/// # Safety
+	///
+	/// See [`Arc::from_raw`]. Additionally, `raw_value` must have been allocated by `allocator` (or an equivalent one).
+	#[must_use = "Implicitly dropping this handle is likely a mistake."]
+	pub unsafe fn from_raw_in(raw_value: NonNull<T>, allocator: A) -> Self {
+		debug_assert_ne!(
+			raw_value.as_ptr().cast::<()>() as usize,
+			0,
+			"Called `tiptoe::Arc::from_raw` with null pointer."
+		);
+		Self {
+			pointer: raw_value,
+			allocator,
+		}
+	}
+
+	/// Constructs a [pinned](`core::pin`) [`Arc`] instance from a compatible value pointer and its allocator.
+	///
+	/// # Safety
+	///
+	/// See [`Arc::pinned_from_raw`]. Additionally, `raw_value` must have been allocated by `allocator` (or an equivalent one).
+	#[must_use = "Implicitly dropping this handle is likely a mistake."]
+	pub unsafe fn pinned_from_raw_in(raw_value: NonNull<T>, allocator: A) -> Pin<Self> {
+		Self::from_raw_in(raw_value, allocator).pipe(|this| Pin::new_unchecked(this))
+	}
+
+	/// Unwraps the payload pointer and allocator contained in the current instance.
+	///
+	/// This does not decrease the reference-count.
+	fn into_raw_parts(this: Self) -> (NonNull<T>, A) {
+		let this = ManuallyDrop::new(this);
+		(this.pointer, unsafe {
+			ptr::addr_of!(this.allocator).read()
+		})
+	}
+
+	/// Returns a reference to the underlying allocator.
+	#[must_use]
+	pub fn allocator(this: &Self) -> &A {
+		&this.allocator
+	}
+
+	/// Creates a new [`Weak`] handle to the payload of this [`Pin<Arc<T, A>>`](`Arc`).
+	///
+	/// This increases the intrusive weak reference-count by 1.
+	#[must_use]
+	pub fn downgrade(this: &Pin<Self>) -> Weak<T, A>
+	where
+		T: Sized,
+		T::RefCounter: WeakRefCounter,
+		A: Clone,
+	{
+		this.ref_counter().downgrade();
+		let this = unsafe { &*ptr::addr_of!(*this).cast::<Self>() };
+		Weak {
+			pointer: this.pointer,
+			ref_counter: this.ref_counter().into(),
+			allocator: this.allocator.clone(),
+		}
+	}
+
+	/// Checks whether two instances of [`Arc<T, A>`](`Arc`) point to the same instance.
+	#[must_use]
+	pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+		ptr::eq(this.pointer.as_ptr(), other.pointer.as_ptr())
+	}
+
+	/// Ensures the payload is exclusively pointed to by this [`Arc<T, A>`](`Arc`), cloning it if necessary,
+	/// and gives access to a [`Pin<&mut T>`] that safely can *not* be used to clone the [`Arc<T, A>`](`Arc`).
+	///
+	/// The clone, if any, is allocated through a clone of this [`Arc`]'s allocator.
+	///
+	/// # Panics
+	///
+	/// Iff [`ManagedClone::managed_clone`] returns an instance with a non-zero reference-count.
+	#[allow(clippy::ref_as_ptr)]
+	pub fn make_mut(this: &mut Pin<Self>) -> ExclusivePin<'_, T>
+	where
+		T: Sized + ManagedClone,
+		A: Clone,
+	{
+		let exclusivity = unsafe { this.ref_counter().acquire() }.unwrap_or_else(|| {
+			*this = unsafe {
+				// Safety:
+				// No effective encapsulation change happens.
+				// `Self::pin_in` does call `IntrusivelyCountable::ref_counter`, but this is legal as that method is not allowed to have effects.
+				let allocator = (*ptr::addr_of!(*this).cast::<Self>()).allocator.clone();
+				Self::pin_in((**this).managed_clone(), allocator)
+			};
+
+			// This could be done faster, but whether that's significant is up to benchmarking it.
+			unsafe { this.ref_counter().acquire() }.expect("unreachable")
+		});
+
+		ExclusivePin::new(exclusivity, unsafe {
+			Pin::new_unchecked((*(this as *mut Pin<Self>).cast::<Self>()).pointer.as_mut())
+		})
+	}
+
+	/// Makes a clone of this [`Arc`], pointing to the same instance, unless it is exclusively borrowed.
+	///
+	/// # Errors
+	///
+	/// Iff called during exclusivity, e.g. on a handle obtained through [`Arc::borrow_from_inner_ref`]
+	/// while an [`ExclusivePin`] for the same instance exists.
+	///
+	/// Unlike [`Clone::clone`], this isn't affected by [`set_exclusivity_violation_handler`](`crate::set_exclusivity_violation_handler`).
+	pub fn try_clone(this: &Self) -> Result<Self, ExclusivityViolation>
+	where
+		A: Clone,
+	{
+		this.ref_counter().try_increment()?;
+		Ok(Self {
+			pointer: this.pointer,
+			allocator: this.allocator.clone(),
+		})
+	}
+
+	/// Makes a clone of this [`Pin<Arc>`](`Pin`), pointing to the same instance, unless it is exclusively borrowed.
+	///
+	/// # Errors
+	///
+	/// Iff called during exclusivity, e.g. on a handle obtained through [`Arc::borrow_pin_from_inner_ref`]
+	/// while an [`ExclusivePin`] for the same instance exists.
+	pub fn try_clone_pinned(this: &Pin<Self>) -> Result<Pin<Self>, ExclusivityViolation>
+	where
+		A: Clone,
+	{
+		Self::try_clone(unsafe { &*ptr::addr_of!(*this).cast::<Self>() })
+			.map(|clone| unsafe { Pin::new_unchecked(clone) })
+	}
+
+	/// Makes `n` clones of this [`Arc`], pointing to the same instance,
+	/// with a single increment of the reference count by `n`.
+	///
+	/// The clones are made as the returned [`CloneN`] is iterated.
+	/// Dropping it early gives up the remaining references at once.
+	///
+	/// # Panics
+	///
+	/// Iff called during exclusivity with nonzero `n`, by default.
+	///
+	/// This behaviour can be changed using [`set_exclusivity_violation_handler`](`crate::set_exclusivity_violation_handler`).
+	///
+	/// # Aborts
+	///
+	/// Iff `n` exceeds `isize::MAX / 2` or the reference count becomes VERY high (for the given target platform).
+	pub fn clone_n(this: &Self, n: usize) -> CloneN<'_, T, A>
+	where
+		A: Clone,
+	{
+		this.ref_counter().increment_n(n);
+		#[cfg(feature = "std")]
+		ref_counter_api::drop_pending::<T::RefCounter>();
+		CloneN {
+			arc: this,
+			remaining: n,
+		}
+	}
+
+	/// Makes `n` clones of this [`Pin<Arc>`](`Pin`), pointing to the same instance,
+	/// with a single increment of the reference count by `n`.
+	///
+	/// See [`Arc::clone_n`].
+	#[allow(clippy::type_complexity)]
+	pub fn clone_n_pinned(
+		this: &Pin<Self>,
+		n: usize,
+	) -> iter::Map<CloneN<'_, T, A>, fn(Self) -> Pin<Self>>
+	where
+		A: Clone,
+	{
+		Self::clone_n(unsafe { &*ptr::addr_of!(*this).cast::<Self>() }, n)
+			.map(|clone| unsafe { Pin::new_unchecked(clone) })
+	}
+
+	/// Checks whether the payload is exclusively pointed to by this [`Arc<T, A>`](`Arc`) and, if this is the case,
+	/// gives access to a [`Pin<&mut T>`] that safely can *not* be used to clone the [`Arc<T, A>`](`Arc`).
+	#[must_use]
+	#[allow(clippy::ref_as_ptr)]
+	pub fn get_mut(this: &mut Pin<Self>) -> Option<ExclusivePin<'_, T>> {
+		unsafe { this.ref_counter().acquire() }.map(|exclusivity| {
+			ExclusivePin::new(exclusivity, unsafe {
+				Pin::new_unchecked((*(this as *mut Pin<Self>).cast::<Self>()).pointer.as_mut())
+			})
+		})
+	}
+
+	/// Attempts to cast this [`Arc`] into once of concrete type `U`.
+	///
+	/// # Errors
+	///
+	/// Iff the underlying instance isn't a `U`.
+	pub fn downcast<U>(this: Self) -> Result<Arc<U, A>, Self>
+	where
+		T: Any,
+		U: Any + IntrusivelyCountable,
+	{
+		if Any::type_id(&*this) == TypeId::of::<U>() {
+			let (pointer, allocator) = Self::into_raw_parts(this);
+			Ok(unsafe { Arc::from_raw_in(pointer.cast(), allocator) })
+		} else {
+			Err(this)
+		}
+	}
+
+	/// Attempts to cast this [`Arc`] into once of concrete type `U`.
+	///
+	/// # Errors
+	///
+	/// Iff the underlying instance isn't a `U`.
+	pub fn downcast_pinned<U>(this: Pin<Self>) -> Result<Pin<Arc<U, A>>, Pin<Self>>
+	where
+		T: Any,
+		U: Any + IntrusivelyCountable,
+	{
+		if Any::type_id(&*this) == TypeId::of::<U>() {
+			let (pointer, allocator) =
+				Self::into_raw_parts(unsafe { Pin::into_inner_unchecked(this) });
+			Ok(unsafe { Arc::pinned_from_raw_in(pointer.cast(), allocator) })
+		} else {
+			Err(this)
+		}
+	}
+
+	/// Converts this [`Arc`] into one of a (usually) dynamically sized type `U`, like `dyn Trait`.
+	///
+	/// Prefer the safe [`unsize!`](`crate::unsize`) macro where possible.
+	///
+	/// # Safety
+	///
+	/// `unsize` must return its argument with only its pointer metadata changed, e.g. through an unsizing coercion,
+	/// and [`U::ref_counter`](`IntrusivelyCountable::ref_counter`) must return the same reference counter as [`T::ref_counter`](`IntrusivelyCountable::ref_counter`).
+	pub unsafe fn unsize<U>(this: Self, unsize: impl FnOnce(*mut T) -> *mut U) -> Arc<U, A>
+	where
+		U: ?Sized + IntrusivelyCountable<RefCounter = T::RefCounter>,
+	{
+		let (pointer, allocator) = Self::into_raw_parts(this);
+		let unsized_ = unsize(pointer.as_ptr());
+		debug_assert_eq!(unsized_.cast::<()>(), pointer.as_ptr().cast::<()>());
+		Arc::from_raw_in(NonNull::new_unchecked(unsized_), allocator)
+	}
+
+	/// Converts this [`Pin<Arc>`] into one of a (usually) dynamically sized type `U`, like `dyn Trait`.
+	///
+	/// Prefer the safe [`unsize!`](`crate::unsize`) macro where possible.
+	///
+	/// # Safety
+	///
+	/// See [`Arc::unsize`].
+	pub unsafe fn unsize_pinned<U>(
+		this: Pin<Self>,
+		unsize: impl FnOnce(*mut T) -> *mut U,
+	) -> Pin<Arc<U, A>>
+	where
+		U: ?Sized + IntrusivelyCountable<RefCounter = T::RefCounter>,
+	{
+		Pin::new_unchecked(Self::unsize(Pin::into_inner_unchecked(this), unsize))
+	}
+}
+
+/// Enables safely recovering a [`Pin<Arc<Self, A>>`](`Arc`) handle from `&self`, like `shared_from_this` in C++.
+///
+/// Whether the instance is currently managed by [`Arc`] is checked at runtime, through its reference count.
+///
+/// The recovered handle's allocator is `A::default()`,
+/// so instances allocated through allocators without a [`Default`] (like [`&Pool`](`crate::pool::Pool`)) aren't supported.
+///
+/// # Safety
+///
+/// Instances of `Self` must only ever be reference-counted by [`Pin<Arc<Self, A>>`](`Arc`) handles
+/// whose allocator can free allocations of `A::default()` and vice versa,
+/// that is: [`Self::ref_counter`](`IntrusivelyCountable::ref_counter`) must not be incremented in any other way.
+///
+/// This is usually ensured by keeping the reference counter private and
+/// only constructing `Self` through a function that returns [`Pin<Arc<Self, A>>`](`Arc`).
+///
+/// # Example
+///
+/// ```rust
+/// use core::pin::Pin;
+/// use tiptoe::{Arc, AtomicTipToe, IntrusivelyCountable, SharedFromThis};
+///
+/// pub struct Node {
+///     counter: AtomicTipToe,
+/// }
+///
+/// impl Node {
+///     pub fn new() -> Pin<Arc<Self>> {
+///         Arc::pin(Self { counter: AtomicTipToe::new() })
+///     }
+/// }
+///
+/// unsafe impl IntrusivelyCountable for Node {
+///     type RefCounter = AtomicTipToe;
+///
+///     fn ref_counter(&self) -> &Self::RefCounter {
+///         &self.counter
+///     }
+/// }
+///
+/// unsafe impl SharedFromThis for Node {}
+///
+/// let node = Node::new();
+/// let node_ref: &Node = &node;
+/// let recovered = node_ref.to_arc();
+/// ```
+pub unsafe trait SharedFromThis<A: Allocator + Default = Global>:
+	IntrusivelyCountable
+{
+	/// Recovers a new [`Pin<Arc<Self, A>>`](`Arc`) handle to this instance.
+	///
+	/// # Panics
+	///
+	/// Iff this instance isn't currently managed by [`Arc`], e.g. before it was pinned or while it's being dropped.
+	///
+	/// During exclusivity, this calls the [exclusivity violation handler](`crate::set_exclusivity_violation_handler`),
+	/// which panics by default.
+	fn to_arc(&self) -> Pin<Arc<Self, A>> {
+		match self.ref_counter().try_increment_if_nonzero() {
+			Ok(true) => unsafe { Arc::pinned_from_raw_in(self.into(), A::default()) },
+			Ok(false) => {
+				panic!("Tried to recover an `Arc` from an instance that isn't managed by one.")
+			}
+			Err(_) => handle_exclusivity_violation(),
+		}
+	}
+
+	/// Recovers a new [`Pin<Arc<Self, A>>`](`Arc`) handle to this instance,
+	/// iff it is currently managed by [`Arc`] and not exclusively borrowed.
+	#[must_use]
+	fn try_to_arc(&self) -> Option<Pin<Arc<Self, A>>> {
+		match self.ref_counter().try_increment_if_nonzero() {
+			Ok(true) => Some(unsafe { Arc::pinned_from_raw_in(self.into(), A::default()) }),
+			Ok(false) | Err(_) => None,
+		}
+	}
+}
+
+/// Wakes a task stored in a [`Pin<Arc<Self>>`](`Arc`), like [`alloc::task::Wake`] but without an extra allocation.
+///
+/// See [`Arc::into_waker`].
+///
+/// # Example
+///
+/// ```rust
+/// use core::{
+///     pin::Pin,
+///     sync::atomic::{AtomicBool, Ordering},
+/// };
+/// use tiptoe::{sync::IntrusiveWake, Arc, AtomicTipToe, IntrusivelyCountable};
+///
+/// #[derive(Default)]
+/// struct Task {
+///     counter: AtomicTipToe,
+///     scheduled: AtomicBool,
+/// }
+///
+/// unsafe impl IntrusivelyCountable for Task {
+///     type RefCounter = AtomicTipToe;
+///
+///     fn ref_counter(&self) -> &Self::RefCounter {
+///         &self.counter
+///     }
+/// }
+///
+/// impl IntrusiveWake for Task {
+///     fn wake(this: Pin<Arc<Self>>) {
+///         Self::wake_by_ref(&this)
+///     }
+///
+///     fn wake_by_ref(this: &Pin<Arc<Self>>) {
+///         this.scheduled.store(true, Ordering::Release)
+///     }
+/// }
+///
+/// let task = Arc::pin(Task::default());
+/// let waker = Arc::into_waker(task.clone());
+///
+/// waker.wake_by_ref();
+/// assert!(task.scheduled.load(Ordering::Acquire));
+/// ```
+pub trait IntrusiveWake: IntrusivelyCountable {
+	/// Wakes this task, consuming the handle.
+	fn wake(this: Pin<Arc<Self>>);
+
+	/// Wakes this task without consuming the handle.
+	///
+	/// The default implementation clones the handle and calls [`IntrusiveWake::wake`].
+	fn wake_by_ref(this: &Pin<Arc<Self>>) {
+		Self::wake(this.clone())
+	}
+}
+
+impl<T: IntrusiveWake> From<Pin<Arc<T>>> for Waker
+where
+	T: Send + Sync + 'static,
+{
+	fn from(value: Pin<Arc<T>>) -> Self {
+		Arc::into_waker(value)
+	}
+}
+
+/// Builds a [`RawWaker`] for `data`, which must be a leaked [`Pin<Arc<T>>`](`Arc`).
+fn raw_waker<T: IntrusiveWake + Send + Sync + 'static>(data: *const ()) -> RawWaker {
+	unsafe fn clone<T: IntrusiveWake + Send + Sync + 'static>(data: *const ()) -> RawWaker {
+		(*data.cast::<T>()).ref_counter().increment();
+		raw_waker::<T>(data)
+	}
+
+	unsafe fn wake<T: IntrusiveWake>(data: *const ()) {
+		T::wake(Arc::pinned_from_raw(NonNull::new_unchecked(data as *mut T)))
+	}
+
+	unsafe fn wake_by_ref<T: IntrusiveWake>(data: *const ()) {
+		let this = ManuallyDrop::new(Arc::pinned_from_raw(NonNull::new_unchecked(data as *mut T)));
+		T::wake_by_ref(&this)
+	}
+
+	unsafe fn drop<T: IntrusiveWake>(data: *const ()) {
+		mem::drop(Arc::pinned_from_raw(NonNull::new_unchecked(data as *mut T)))
+	}
+
+	RawWaker::new(
+		data,
+		&RawWakerVTable::new(clone::<T>, wake::<T>, wake_by_ref::<T>, drop::<T>),
+	)
+}
+
+/// An iterator over clones of an [`Arc`] that were counted all at once, as returned by [`Arc::clone_n`].
+///
+/// Dropping this gives up the references of the clones that weren't taken.
+pub struct CloneN<'a, T: ?Sized + IntrusivelyCountable, A: Allocator + Clone = Global> {
+	arc: &'a Arc<T, A>,
+	remaining: usize,
+}
+
+impl<T: ?Sized + IntrusivelyCountable, A: Allocator + Clone> Iterator for CloneN<'_, T, A> {
+	type Item = Arc<T, A>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.remaining = self.remaining.checked_sub(1)?;
+		Some(Arc {
+			pointer: self.arc.pointer,
+			allocator: self.arc.allocator.clone(),
+		})
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(self.remaining, Some(self.remaining))
+	}
+}
+
+impl<T: ?Sized + IntrusivelyCountable, A: Allocator + Clone> ExactSizeIterator
+	for CloneN<'_, T, A>
+{
+}
+
+impl<T: ?Sized + IntrusivelyCountable, A: Allocator + Clone> FusedIterator for CloneN<'_, T, A> {}
+
+impl<T: ?Sized + IntrusivelyCountable, A: Allocator + Clone> Drop for CloneN<'_, T, A> {
+	fn drop(&mut self) {
+		if self.remaining != 0 {
+			// The borrowed `Arc` keeps the payload alive.
+			unsafe { Arc::drop_n(self.arc.pointer, self.remaining, &self.arc.allocator) }
+		}
+	}
+}
+
+impl<T: ?Sized + IntrusivelyCountable, A: Allocator + Clone> Debug for CloneN<'_, T, A>
+where
+	T: Debug,
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.debug_struct("CloneN")
+			.field("arc", self.arc)
+			.field("remaining", &self.remaining)
+			.finish()
+	}
+}
+
+/// A weak handle to a [`Pin<Arc<T, A>>`](`Arc`)-managed instance.
+///
+/// This keeps the allocation, but not the payload, alive.
+/// The reference counter itself remains accessible after the payload was dropped.
+pub struct Weak<T: IntrusivelyCountable, A: Allocator = Global>
+where
+	T::RefCounter: WeakRefCounter,
+{
+	pointer: NonNull<T>,
+	ref_counter: NonNull<T::RefCounter>,
+	allocator: A,
+}
+
+impl<T: IntrusivelyCountable, A: Allocator + Clone> Clone for Weak<T, A>
+where
+	T::RefCounter: WeakRefCounter,
+{
+	/// Makes a clone of this [`Weak`], pointing to the same allocation.
+	///
+	/// This increases the weak reference count by 1.
+	fn clone(&self) -> Self {
+		unsafe { self.ref_counter.as_ref() }.increment_weak();
+		Self {
+			pointer: self.pointer,
+			ref_counter: self.ref_counter,
+			allocator: self.allocator.clone(),
+		}
+	}
+}
+
+impl<T: IntrusivelyCountable, A: Allocator> Debug for Weak<T, A>
+where
+	T::RefCounter: WeakRefCounter,
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.write_str("(Weak)")
+	}
+}
+
+impl<T: IntrusivelyCountable, A: Allocator> Drop for Weak<T, A>
+where
+	T::RefCounter: WeakRefCounter,
+{
+	fn drop(&mut self) {
+		unsafe {
+			if self.ref_counter.as_ref().decrement_weak() {
+				self.allocator
+					.deallocate(self.pointer.cast(), Layout::new::<T>())
+			}
+		}
+	}
+}
+
+impl<T: IntrusivelyCountable, A: Allocator> Pointer for Weak<T, A>
+where
+	T::RefCounter: WeakRefCounter,
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		Pointer::fmt(&self.pointer, f)
+	}
+}
+
+unsafe impl<T: IntrusivelyCountable, A: Allocator> Send for Weak<T, A>
+where
+	T: Sync + Send,
+	T::RefCounter: WeakRefCounter,
+	A: Send,
+{
+}
+unsafe impl<T: IntrusivelyCountable, A: Allocator> Sync for Weak<T, A>
+where
+	T: Sync + Send,
+	T::RefCounter: WeakRefCounter,
+	A: Sync,
+{
+}
+impl<T: IntrusivelyCountable, A: Allocator> Unpin for Weak<T, A> where T::RefCounter: WeakRefCounter {}
+
+impl<T: IntrusivelyCountable, A: Allocator> Weak<T, A>
+where
+	T::RefCounter: WeakRefCounter,
+{
+	/// Attempts to create a new [`Pin<Arc<T, A>>`](`Arc`) to the payload.
+	///
+	/// Returns [`None`] iff the payload was already dropped or is currently mutably borrowed
+	/// through [`Arc::get_mut`] or [`Arc::make_mut`].
+	#[must_use]
+	pub fn upgrade(&self) -> Option<Pin<Arc<T, A>>>
+	where
+		A: Clone,
+	{
+		unsafe { self.ref_counter.as_ref() }
+			.upgrade()
+			.then(|| unsafe { Arc::pinned_from_raw_in(self.pointer, self.allocator.clone()) })
+	}
+
+	/// Checks whether two instances of [`Weak<T, A>`](`Weak`) point to the same allocation.
+	#[must_use]
+	pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+		this.pointer == other.pointer
+	}
+}
+
+/// A borrowed [`Arc<T, A>`](`Arc`) handle that can be copied around without touching the reference count.
+///
+/// This is useful to pass an [`Arc`]-managed instance into functions that only sometimes need to retain it,
+/// as [`ArcBorrow::clone_arc`] can upgrade it to an owned [`Arc`] without a double reference.
+///
+/// Handles created through [`ArcBorrow::new_pinned`] or [`ArcBorrow::pinned_from_inner_ref`] are [pinned](`core::pin`),
+/// and can be upgraded to an owned [`Pin<Arc<T, A>>`](`Arc`) through [`ArcBorrow::clone_pinned`].
+pub struct ArcBorrow<'a, T: ?Sized + IntrusivelyCountable, A: Allocator = Global> {
+	pointer: NonNull<T>,
+	allocator: &'a A,
+}
+
+impl<T: ?Sized + IntrusivelyCountable, A: Allocator> Clone for ArcBorrow<'_, T, A> {
+	fn clone(&self) -> Self {
+		*self
+	}
+}
+
+impl<T: ?Sized + IntrusivelyCountable, A: Allocator> Copy for ArcBorrow<'_, T, A> {}
+
+impl<T: ?Sized + IntrusivelyCountable, A: Allocator> Debug for ArcBorrow<'_, T, A>
+where
+	T: Debug,
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.debug_tuple("ArcBorrow").field(&&**self).finish()
+	}
+}
+
+impl<T: ?Sized + IntrusivelyCountable, A: Allocator> Deref for ArcBorrow<'_, T, A> {
+	type Target = T;
+
+	fn deref(&self) -> &Self::Target {
+		unsafe { self.pointer.as_ref() }
+	}
+}
+
+impl<T: ?Sized + IntrusivelyCountable, A: Allocator> Display for ArcBorrow<'_, T, A>
+where
+	T: Display,
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		(**self).fmt(f)
+	}
+}
+
+impl<'a, T: ?Sized + IntrusivelyCountable, A: Allocator> From<&'a Arc<T, A>>
+	for ArcBorrow<'a, T, A>
+{
+	fn from(arc: &'a Arc<T, A>) -> Self {
+		Self::new(arc)
+	}
+}
+
+impl<'a, T: ?Sized + IntrusivelyCountable, A: Allocator> From<&'a Pin<Arc<T, A>>>
+	for Pin<ArcBorrow<'a, T, A>>
+{
+	fn from(arc: &'a Pin<Arc<T, A>>) -> Self {
+		ArcBorrow::new_pinned(arc)
+	}
+}
+
+impl<T: ?Sized + IntrusivelyCountable, A: Allocator> Pointer for ArcBorrow<'_, T, A> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		Pointer::fmt(&self.pointer, f)
+	}
+}
+
+unsafe impl<T: ?Sized + IntrusivelyCountable, A: Allocator> Send for ArcBorrow<'_, T, A>
+where
+	T: Sync + Send,
+	A: Sync,
+{
+}
+unsafe impl<T: ?Sized + IntrusivelyCountable, A: Allocator> Sync for ArcBorrow<'_, T, A>
+where
+	T: Sync + Send,
+	A: Sync,
+{
+}
+impl<T: ?Sized + IntrusivelyCountable, A: Allocator> Unpin for ArcBorrow<'_, T, A> {}
+
+impl<'a, T: ?Sized + IntrusivelyCountable> ArcBorrow<'a, T> {
+	/// Unsafely borrows a shared reference to an [`Arc`]-managed instance as [`ArcBorrow`].
+	///
+	/// # Safety
+	///
+	/// `inner` must be a reference to an instance managed by an *unpinned* [`Arc<T>`](`Arc`).
+	#[must_use]
+	pub unsafe fn from_inner_ref(inner: &'a T) -> Self {
+		Self {
+			pointer: inner.into(),
+			allocator: &Global,
+		}
+	}
+
+	/// Unsafely borrows a shared reference to a [`Pin<Arc>`]-managed instance as [`Pin<ArcBorrow>`](`ArcBorrow`).
+	///
+	/// # Safety
+	///
+	/// `inner` must be a reference to an instance managed by [`Pin<Arc<T>>`](`Arc`).
+	#[must_use]
+	pub unsafe fn pinned_from_inner_ref(inner: &'a T) -> Pin<Self> {
+		Pin::new_unchecked(Self::from_inner_ref(inner))
+	}
+}
+
+impl<'a, T: ?Sized + IntrusivelyCountable, A: Allocator> ArcBorrow<'a, T, A> {
+	/// Borrows `arc` without touching the reference count.
+	#[must_use]
+	pub fn new(arc: &'a Arc<T, A>) -> Self {
+		Self {
+			pointer: arc.pointer,
+			allocator: &arc.allocator,
+		}
+	}
+
+	/// Borrows the pinned `arc` without touching the reference count.
+	#[must_use]
+	pub fn new_pinned(arc: &'a Pin<Arc<T, A>>) -> Pin<Self> {
+		unsafe { Pin::new_unchecked(Self::new(&*ptr::addr_of!(*arc).cast::<Arc<T, A>>())) }
+	}
+
+	/// Gets a reference to the payload that is valid for the whole borrow (rather than just that of `this`).
+	#[must_use]
+	pub fn get(this: Self) -> &'a T {
+		unsafe { this.pointer.as_ref() }
+	}
+
+	/// Upgrades this borrow to an owned [`Arc<T, A>`](`Arc`), pointing to the same instance.
+	///
+	/// This increases the strong reference count by 1.
+	#[must_use]
+	pub fn clone_arc(this: Self) -> Arc<T, A>
+	where
+		A: Clone,
+	{
+		this.ref_counter().increment();
+		Arc {
+			pointer: this.pointer,
+			allocator: this.allocator.clone(),
+		}
+	}
+
+	/// Upgrades this pinned borrow to an owned [`Pin<Arc<T, A>>`](`Arc`), pointing to the same instance.
+	///
+	/// This increases the strong reference count by 1.
+	#[must_use]
+	pub fn clone_pinned(this: Pin<Self>) -> Pin<Arc<T, A>>
+	where
+		A: Clone,
+	{
+		unsafe { Pin::new_unchecked(Self::clone_arc(Pin::into_inner_unchecked(this))) }
+	}
+
+	/// Checks whether two instances of [`ArcBorrow<T, A>`](`ArcBorrow`) point to the same instance.
+	#[must_use]
+	pub fn ptr_eq(this: Self, other: Self) -> bool {
+		this.pointer.cast::<()>() == other.pointer.cast::<()>()
+	}
+}
+
+/// A statically exclusive handle to an [`Arc`]-compatible allocation, for mutating a payload before sharing it.
+///
+/// This doesn't touch the reference count until converted through [`UniqueArc::shareable`] or [`UniqueArc::shareable_pinned`],
+/// which don't reallocate.
+///
+/// Use [`UniqueArc::pin`] or [`UniqueArc::pin_in`] and [`Pin::as_mut`] to mutate [`!Unpin`](`Unpin`) payloads in place.
+pub struct UniqueArc<T: ?Sized + IntrusivelyCountable, A: Allocator = Global> {
+	box_: allocator_api2::boxed::Box<T, A>,
+}
+
+impl<T: ?Sized + IntrusivelyCountable, A: Allocator> Debug for UniqueArc<T, A>
+where
+	T: Debug,
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.debug_tuple("UniqueArc").field(&&**self).finish()
+	}
+}
+
+impl<T: IntrusivelyCountable> Default for UniqueArc<T>
+where
+	T: Default,
+{
+	fn default() -> Self {
+		Self::new(T::default())
+	}
+}
+
+impl<T: ?Sized + IntrusivelyCountable, A: Allocator> Deref for UniqueArc<T, A> {
+	type Target = T;
+
+	fn deref(&self) -> &Self::Target {
+		&self.box_
+	}
+}
+
+impl<T: ?Sized + IntrusivelyCountable, A: Allocator> DerefMut for UniqueArc<T, A> {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		&mut self.box_
+	}
+}
+
+impl<T: ?Sized + IntrusivelyCountable, A: Allocator> Display for UniqueArc<T, A>
+where
+	T: Display,
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		(**self).fmt(f)
+	}
+}
+
+impl<T: ?Sized + IntrusivelyCountable, A: Allocator> From<UniqueArc<T, A>> for Arc<T, A> {
+	fn from(unique: UniqueArc<T, A>) -> Self {
+		UniqueArc::shareable(unique)
+	}
+}
+
+impl<T: ?Sized + IntrusivelyCountable, A: Allocator> From<Pin<UniqueArc<T, A>>> for Pin<Arc<T, A>> {
+	fn from(unique: Pin<UniqueArc<T, A>>) -> Self {
+		UniqueArc::shareable_pinned(unique)
+	}
+}
+
+impl<T: ?Sized + IntrusivelyCountable, A: Allocator> Pointer for UniqueArc<T, A> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		Pointer::fmt(&&**self, f)
+	}
+}
+
+impl<T: ?Sized + IntrusivelyCountable, A: Allocator> Unpin for UniqueArc<T, A> {}
+
+impl<T: IntrusivelyCountable> UniqueArc<T> {
+	/// Creates a new instance of [`UniqueArc<_>`](`UniqueArc`) by moving `value` into a new heap allocation.
+	#[must_use]
+	pub fn new(value: T) -> Self {
+		Self::new_in(value, Global)
+	}
+
+	/// Creates a new instance of [`Pin<UniqueArc<_>>`](`UniqueArc`) by moving `value` into a new heap allocation.
+	#[must_use]
+	pub fn pin(value: T) -> Pin<Self> {
+		Self::pin_in(value, Global)
+	}
+}
+
+impl<T: IntrusivelyCountable, A: Allocator> UniqueArc<T, A> {
+	/// Creates a new instance of [`UniqueArc<_, A>`](`UniqueArc`) by moving `value` into a new allocation from `allocator`.
+	#[must_use]
+	pub fn new_in(value: T, allocator: A) -> Self {
+		Self {
+			box_: allocator_api2::boxed::Box::new_in(value, allocator),
+		}
+	}
+
+	/// Creates a new instance of [`Pin<UniqueArc<_, A>>`](`UniqueArc`) by moving `value` into a new allocation from `allocator`.
+	#[must_use]
+	pub fn pin_in(value: T, allocator: A) -> Pin<Self> {
+		unsafe { Pin::new_unchecked(Self::new_in(value, allocator)) }
+	}
+
+	/// Moves the payload out of its allocation.
+	#[must_use]
+	pub fn into_inner(this: Self) -> T {
+		allocator_api2::boxed::Box::into_inner(this.box_)
+	}
+}
+
+impl<T: ?Sized + IntrusivelyCountable, A: Allocator> UniqueArc<T, A> {
+	/// Converts this [`UniqueArc<T, A>`](`UniqueArc`) into a shareable [`Arc<T, A>`](`Arc`) without reallocating.
+	///
+	/// This increases the intrusive reference-count by 1.
+	///
+	/// Calling this method with an instance with non-zero reference-count is safe,
+	/// but likely to lead to memory leaks (or the process being aborted, if the recorded count is very high).
+	#[must_use]
+	pub fn shareable(this: Self) -> Arc<T, A> {
+		this.box_.into()
+	}
+
+	/// Converts this [`Pin<UniqueArc<T, A>>`](`UniqueArc`) into a shareable [`Pin<Arc<T, A>>`](`Arc`) without reallocating.
+	///
+	/// This increases the intrusive reference-count by 1.
+	///
+	/// Calling this method with an instance with non-zero reference-count is safe,
+	/// but likely to lead to memory leaks (or the process being aborted, if the recorded count is very high).
+	#[must_use]
+	pub fn shareable_pinned(this: Pin<Self>) -> Pin<Arc<T, A>> {
+		unsafe { Pin::new_unchecked(Self::shareable(Pin::into_inner_unchecked(this))) }
+	}
+
+	/// Returns a reference to the underlying allocator.
+	#[must_use]
+	pub fn allocator(this: &Self) -> &A {
+		allocator_api2::boxed::Box::allocator(&this.box_)
+	}
+}
+
+/// A handle to part of an [`Arc`]-managed instance (usually a field), which keeps the whole instance alive.
+///
+/// [`Clone`] and [`Drop`] go through the owning instance's reference counter,
+/// while [`Deref`] and comparisons go to the mapped `U`.
+///
+/// The owning [`Arc`] isn't accessible through this handle, so a [`Pin<MappedArc>`](`MappedArc`) can be created from a
+/// [`Pin<Arc>`] through [`MappedArc::new_pinned`] (structural pin projection) without breaking its pinning guarantees.
+///
+/// # Example
+///
+/// ```rust
+/// use tiptoe::{Arc, AtomicTipToe, IntrusivelyCountable, MappedArc};
+///
+/// struct Config {
+///     counter: AtomicTipToe,
+///     database: Database,
+/// }
+///
+/// struct Database {
+///     url: String,
+/// }
+///
+/// unsafe impl IntrusivelyCountable for Config {
+///     type RefCounter = AtomicTipToe;
+///
+///     fn ref_counter(&self) -> &Self::RefCounter {
+///         &self.counter
+///     }
+/// }
+///
+/// let config = Arc::new(Config {
+///     counter: AtomicTipToe::new(),
+///     database: Database {
+///         url: "localhost".to_string(),
+///     },
+/// });
+///
+/// let database = MappedArc::new(config, |config| &config.database);
+/// let url = MappedArc::map(database.clone(), |database| database.url.as_str());
+/// drop(database);
+///
+/// assert_eq!(&*url, "localhost");
+/// ```
+pub struct MappedArc<T: ?Sized + IntrusivelyCountable, U: ?Sized, A: Allocator = Global> {
+	/// Possibly pinned.
+	owner: Arc<T, A>,
+	pointer: NonNull<U>,
+}
+
+impl<T: ?Sized + IntrusivelyCountable, U: ?Sized, A: Allocator> AsRef<U> for MappedArc<T, U, A> {
+	fn as_ref(&self) -> &U {
+		self
+	}
+}
+
+impl<T: ?Sized + IntrusivelyCountable, U: ?Sized, A: Allocator> Borrow<U> for MappedArc<T, U, A> {
+	fn borrow(&self) -> &U {
+		self
+	}
+}
+
+impl<T: ?Sized + IntrusivelyCountable, U: ?Sized, A: Allocator + Clone> Clone
+	for MappedArc<T, U, A>
+{
+	/// Makes a clone of this [`MappedArc`], pointing to the same instance.
+	///
+	/// This increases the owning instance's strong reference count by 1.
+	fn clone(&self) -> Self {
+		Self {
+			owner: self.owner.clone(),
+			pointer: self.pointer,
+		}
+	}
+}
+
+impl<T: ?Sized + IntrusivelyCountable, U: ?Sized, A: Allocator> Debug for MappedArc<T, U, A>
+where
+	U: Debug,
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.debug_tuple("MappedArc").field(&&**self).finish()
+	}
+}
+
+impl<T: ?Sized + IntrusivelyCountable, U: ?Sized, A: Allocator> Deref for MappedArc<T, U, A> {
+	type Target = U;
+
+	fn deref(&self) -> &Self::Target {
+		unsafe { self.pointer.as_ref() }
+	}
+}
+
+impl<T: ?Sized + IntrusivelyCountable, U: ?Sized, A: Allocator> Display for MappedArc<T, U, A>
+where
+	U: Display,
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		(**self).fmt(f)
+	}
+}
+
+impl<T: ?Sized + IntrusivelyCountable, U: ?Sized, A: Allocator> Eq for MappedArc<T, U, A> where U: Eq
+{}
+
+impl<T: ?Sized + IntrusivelyCountable, U: ?Sized, A: Allocator> Hash for MappedArc<T, U, A>
+where
+	U: Hash,
+{
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		(**self).hash(state)
+	}
+}
+
+impl<T: ?Sized + IntrusivelyCountable, U: ?Sized, A: Allocator> Ord for MappedArc<T, U, A>
+where
+	U: Ord,
+{
+	fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+		(**self).cmp(other)
+	}
+}
+
+impl<
+		T: ?Sized + IntrusivelyCountable,
+		U: ?Sized,
+		A: Allocator,
+		O: ?Sized + IntrusivelyCountable,
+		V: ?Sized,
+		B: Allocator,
+	> PartialEq<MappedArc<O, V, B>> for MappedArc<T, U, A>
+where
+	U: PartialEq<V>,
+{
+	fn eq(&self, other: &MappedArc<O, V, B>) -> bool {
+		(**self) == (**other)
+	}
+}
+
+impl<
+		T: ?Sized + IntrusivelyCountable,
+		U: ?Sized,
+		A: Allocator,
+		O: ?Sized + IntrusivelyCountable,
+		V: ?Sized,
+		B: Allocator,
+	> PartialOrd<MappedArc<O, V, B>> for MappedArc<T, U, A>
+where
+	U: PartialOrd<V>,
+{
+	fn partial_cmp(&self, other: &MappedArc<O, V, B>) -> Option<core::cmp::Ordering> {
+		(**self).partial_cmp(other)
+	}
+}
+
+impl<T: ?Sized + IntrusivelyCountable, U: ?Sized, A: Allocator> Pointer for MappedArc<T, U, A> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		Pointer::fmt(&self.pointer, f)
+	}
+}
+
+unsafe impl<T: ?Sized + IntrusivelyCountable, U: ?Sized, A: Allocator> Send for MappedArc<T, U, A>
+where
+	Arc<T, A>: Send,
+	U: Sync,
+{
+}
+unsafe impl<T: ?Sized + IntrusivelyCountable, U: ?Sized, A: Allocator> Sync for MappedArc<T, U, A>
+where
+	Arc<T, A>: Sync,
+	U: Sync,
+{
+}
+impl<T: ?Sized + IntrusivelyCountable, U: ?Sized, A: Allocator> Unpin for MappedArc<T, U, A> {}
+
+impl<T: ?Sized + IntrusivelyCountable, U: ?Sized, A: Allocator> MappedArc<T, U, A> {
+	/// Maps `owner` to part of its payload, usually a field.
+	///
+	/// This doesn't change the reference count.
+	#[must_use]
+	pub fn new(owner: Arc<T, A>, f: impl FnOnce(&T) -> &U) -> Self {
+		let pointer = f(&owner).into();
+		Self { owner, pointer }
+	}
+
+	/// Maps the pinned `owner` to part of its payload, usually a structurally pinned field.
+	///
+	/// This doesn't change the reference count.
+	#[must_use]
+	pub fn new_pinned(owner: Pin<Arc<T, A>>, f: impl FnOnce(Pin<&T>) -> Pin<&U>) -> Pin<Self> {
+		let pointer = Pin::get_ref(f(owner.as_ref())).into();
+		unsafe {
+			Pin::new_unchecked(Self {
+				owner: Pin::into_inner_unchecked(owner),
+				pointer,
+			})
+		}
+	}
+
+	/// Maps this handle further, to part of `U`.
+	///
+	/// This doesn't change the reference count.
+	#[must_use]
+	pub fn map<V: ?Sized>(this: Self, f: impl FnOnce(&U) -> &V) -> MappedArc<T, V, A> {
+		let pointer = f(&this).into();
+		MappedArc {
+			owner: this.owner,
+			pointer,
+		}
+	}
+
+	/// Maps this pinned handle further, to part of `U`, usually a structurally pinned field.
+	///
+	/// This doesn't change the reference count.
+	#[must_use]
+	pub fn map_pinned<V: ?Sized>(
+		this: Pin<Self>,
+		f: impl FnOnce(Pin<&U>) -> Pin<&V>,
+	) -> Pin<MappedArc<T, V, A>> {
+		let pointer = Pin::get_ref(f(this.as_ref())).into();
+		let this = unsafe { Pin::into_inner_unchecked(this) };
+		unsafe {
+			Pin::new_unchecked(MappedArc {
+				owner: this.owner,
+				pointer,
+			})
+		}
+	}
+
+	/// Gives access to the owning instance.
+	#[must_use]
+	pub fn owner(this: &Self) -> &T {
+		&this.owner
+	}
+
+	/// Gives pinned access to the owning instance.
+	#[must_use]
+	pub fn owner_pinned(this: &Pin<Self>) -> Pin<&T> {
+		unsafe { Pin::new_unchecked(&(*ptr::addr_of!(*this).cast::<Self>()).owner) }
+	}
+
+	/// Checks whether two instances of [`MappedArc<T, U, A>`](`MappedArc`) point to the same `U`.
+	#[must_use]
+	pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+		this.pointer.cast::<()>() == other.pointer.cast::<()>()
+	}
+}
+
+/// A thin (one pointer wide) [`Arc`] to a [`HeaderSlice`] payload, which stores its length inline.
+///
+/// Converts to and from the fat-pointer [`Arc<Counted<HeaderSlice<H, [T]>, C>>`](`Arc`) without reallocating.
+///
+/// # Example
+///
+/// ```rust
+/// use core::mem::size_of;
+/// use tiptoe::sync::ThinArc;
+///
+/// let thin = ThinArc::<_, _>::from_header_and_iter("header", 0..5_u8);
+/// assert_eq!(size_of::<ThinArc<&str, u8>>(), size_of::<usize>());
+///
+/// assert_eq!(thin.header, "header");
+/// assert_eq!(&thin.slice, &[0, 1, 2, 3, 4]);
+/// ```
+pub struct ThinArc<H, T, C: RefCounterFor<HeaderSlice<H, [T]>> = AtomicTipToe> {
+	pointer: NonNull<Counted<HeaderSlice<H, [T; 0]>, C>>,
+}
+
+impl<H, T, C: RefCounterFor<HeaderSlice<H, [T]>>> Clone for ThinArc<H, T, C> {
+	/// Makes a clone of this [`ThinArc`], pointing to the same instance.
+	///
+	/// This increases the strong reference count by 1.
+	fn clone(&self) -> Self {
+		self.counted().ref_counter().increment();
+		Self {
+			pointer: self.pointer,
+		}
+	}
+}
+
+impl<H, T, C: RefCounterFor<HeaderSlice<H, [T]>>> Debug for ThinArc<H, T, C>
+where
+	H: Debug,
+	T: Debug,
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.debug_tuple("ThinArc").field(&&**self).finish()
+	}
+}
+
+impl<H, T, C: RefCounterFor<HeaderSlice<H, [T]>>> Deref for ThinArc<H, T, C> {
+	type Target = HeaderSlice<H, [T]>;
+
+	fn deref(&self) -> &Self::Target {
+		self.counted()
+	}
+}
+
+impl<H, T, C: RefCounterFor<HeaderSlice<H, [T]>>> Drop for ThinArc<H, T, C> {
+	fn drop(&mut self) {
+		drop(unsafe { Arc::from_raw(Counted::from_thin(self.pointer)) })
+	}
+}
+
+impl<H, T, C: RefCounterFor<HeaderSlice<H, [T]>>> Eq for ThinArc<H, T, C>
+where
+	H: Eq,
+	T: Eq,
+{
+}
+
+impl<H, T, C: RefCounterFor<HeaderSlice<H, [T]>>> From<Arc<Counted<HeaderSlice<H, [T]>, C>>>
+	for ThinArc<H, T, C>
+{
+	/// Converts into a [`ThinArc`] without reallocating.
+	fn from(arc: Arc<Counted<HeaderSlice<H, [T]>, C>>) -> Self {
+		Self {
+			pointer: Arc::leak(arc).cast(),
+		}
+	}
+}
+
+impl<H, T, C: RefCounterFor<HeaderSlice<H, [T]>>> From<ThinArc<H, T, C>>
+	for Arc<Counted<HeaderSlice<H, [T]>, C>>
+{
+	/// Converts into a fat-pointer [`Arc`] without reallocating.
+	fn from(thin: ThinArc<H, T, C>) -> Self {
+		let thin = ManuallyDrop::new(thin);
+		unsafe { Arc::from_raw(Counted::from_thin(thin.pointer)) }
+	}
+}
+
+impl<H, T, C: RefCounterFor<HeaderSlice<H, [T]>>> Hash for ThinArc<H, T, C>
+where
+	H: Hash,
+	T: Hash,
+{
+	fn hash<X: Hasher>(&self, state: &mut X) {
+		(**self).hash(state)
+	}
+}
+
+impl<H, T, C: RefCounterFor<HeaderSlice<H, [T]>>> PartialEq for ThinArc<H, T, C>
+where
+	H: PartialEq,
+	T: PartialEq,
+{
+	fn eq(&self, other: &Self) -> bool {
+		(**self) == (**other)
+	}
+}
+
+impl<H, T, C: RefCounterFor<HeaderSlice<H, [T]>>> Pointer for ThinArc<H, T, C> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		Pointer::fmt(&self.pointer, f)
+	}
+}
+
+unsafe impl<H, T, C: RefCounterFor<HeaderSlice<H, [T]>>> Send for ThinArc<H, T, C> where
+	Arc<Counted<HeaderSlice<H, [T]>, C>>: Send
+{
+}
+unsafe impl<H, T, C: RefCounterFor<HeaderSlice<H, [T]>>> Sync for ThinArc<H, T, C> where
+	Arc<Counted<HeaderSlice<H, [T]>, C>>: Sync
+{
+}
+impl<H, T, C: RefCounterFor<HeaderSlice<H, [T]>>> Unpin for ThinArc<H, T, C> {}
+
+impl<H, T, C: RefCounterFor<HeaderSlice<H, [T]>>> ThinArc<H, T, C> {
+	/// Creates a new [`ThinArc`] by moving `header` and the items of `items` into a new heap allocation.
+	///
+	/// # Panics
+	///
+	/// Iff `items` yields fewer items than its [`ExactSizeIterator::len`].
+	#[must_use]
+	pub fn from_header_and_iter<I>(header: H, items: I) -> Self
+	where
+		C: Default,
+		I: IntoIterator<Item = T>,
+		I::IntoIter: ExactSizeIterator,
+	{
+		Arc::from(Counted::from_header_and_iter(header, items)).into()
+	}
+
+	/// Creates a new [`ThinArc`] by moving `header` and the items of `items` into a new heap allocation,
+	/// without aborting if that fails.
+	///
+	/// # Errors
+	///
+	/// Iff the allocation fails, in which case `header` and the unconsumed iterator are handed back through the [`AllocError`].
+	///
+	/// # Panics
+	///
+	/// Iff `items` yields fewer items than its [`ExactSizeIterator::len`].
+	pub fn try_from_header_and_iter<I>(
+		header: H,
+		items: I,
+	) -> Result<Self, AllocError<(H, I::IntoIter)>>
+	where
+		C: Default,
+		I: IntoIterator<Item = T>,
+		I::IntoIter: ExactSizeIterator,
+	{
+		Counted::try_from_header_and_iter(header, items).map(|counted| Arc::from(counted).into())
+	}
+
+	/// Checks whether two instances of [`ThinArc<H, T, C>`](`ThinArc`) point to the same instance.
+	#[must_use]
+	pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+		this.pointer == other.pointer
+	}
+
+	fn counted(&self) -> &Counted<HeaderSlice<H, [T]>, C> {
+		unsafe { Counted::from_thin(self.pointer).as_ref() }
+	}
+}
+
+/// An atomically swappable [`Option<Pin<Arc<T>>>`](`Arc`) slot, for example for hot configuration.
+///
+/// Loads are safe against a concurrent final decrement:
+/// The low bits of the stored pointer (below the alignment of `T`) count in-flight loads,
+/// which a swapping thread transfers to the intrusive reference-count of the previous payload.
+///
+/// This doesn't require any allocation, but bounds the number of loads that can be in flight at once
+/// to `align_of::<T>() - 1`, e.g. 7 for an 8-byte-aligned payload.
+/// Further concurrent loads (and swaps, which load first) spin until one of those finishes,
+/// so **loads are only lock-free up to that bound**.
+///
+/// See [`AtomicArc`] for a slot that can't be empty.
+///
+/// # Example
+///
+/// ```rust
+/// use tiptoe::{sync::AtomicOptionArc, Arc, AtomicTipToe, IntrusivelyCountable};
+///
+/// #[derive(Default)]
+/// struct Config {
+///     counter: AtomicTipToe,
+///     verbose: bool,
+/// }
+///
+/// unsafe impl IntrusivelyCountable for Config {
+///     type RefCounter = AtomicTipToe;
+///
+///     fn ref_counter(&self) -> &Self::RefCounter {
+///         &self.counter
+///     }
+/// }
+///
+/// let slot = AtomicOptionArc::default();
+/// assert!(slot.load().is_none());
+///
+/// slot.store(Some(Arc::pin(Config::default())));
+/// let previous = slot.rcu(|_| {
+///     Some(Arc::pin(Config {
+///         verbose: true,
+///         ..Config::default()
+///     }))
+/// });
+///
+/// assert!(!previous.unwrap().verbose);
+/// assert!(slot.load().unwrap().verbose);
+/// ```
+pub struct AtomicOptionArc<T: IntrusivelyCountable> {
+	/// A leaked [`Pin<Arc<T>>`] or null, tagged with the number of in-flight loads.
+	tagged: AtomicPtr<T>,
+	_phantom: PhantomData<Option<Pin<Arc<T>>>>,
+}
+
+impl<T: IntrusivelyCountable> Debug for AtomicOptionArc<T>
+where
+	T: Debug,
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.debug_tuple("AtomicOptionArc")
+			.field(&self.load())
+			.finish()
+	}
+}
+
+impl<T: IntrusivelyCountable> Default for AtomicOptionArc<T> {
+	fn default() -> Self {
+		Self::new(None)
+	}
+}
+
+impl<T: IntrusivelyCountable> Drop for AtomicOptionArc<T> {
+	fn drop(&mut self) {
+		drop(unsafe { Self::from_ptr(Self::untag(*self.tagged.get_mut())) })
+	}
+}
+
+impl<T: IntrusivelyCountable> From<Option<Pin<Arc<T>>>> for AtomicOptionArc<T> {
+	fn from(value: Option<Pin<Arc<T>>>) -> Self {
+		Self::new(value)
+	}
+}
+
+impl<T: IntrusivelyCountable> From<Pin<Arc<T>>> for AtomicOptionArc<T> {
+	fn from(value: Pin<Arc<T>>) -> Self {
+		Self::new(Some(value))
+	}
+}
+
+unsafe impl<T: IntrusivelyCountable> Send for AtomicOptionArc<T> where Arc<T>: Send + Sync {}
+unsafe impl<T: IntrusivelyCountable> Sync for AtomicOptionArc<T> where Arc<T>: Send + Sync {}
+
+impl<T: IntrusivelyCountable> AtomicOptionArc<T> {
+	/// The bits of the stored pointer that count in-flight loads.
+	const TAG_MASK: usize = {
+		assert!(
+			mem::align_of::<T>() > 1,
+			"`AtomicOptionArc` requires payloads aligned to at least 2 bytes."
+		);
+		mem::align_of::<T>() - 1
+	};
+
+	/// Creates a new [`AtomicOptionArc`] containing `value`.
+	#[must_use]
+	pub fn new(value: Option<Pin<Arc<T>>>) -> Self {
+		Self {
+			tagged: AtomicPtr::new(Self::into_ptr(value)),
+			_phantom: PhantomData,
+		}
+	}
+
+	/// Unwraps the contained value.
+	#[must_use]
+	pub fn into_inner(self) -> Option<Pin<Arc<T>>> {
+		let mut this = ManuallyDrop::new(self);
+		unsafe { Self::from_ptr(Self::untag(*this.tagged.get_mut())) }
+	}
+
+	/// Loads a new handle to the current value.
+	///
+	/// This spins while `align_of::<T>() - 1` other loads are in flight.
+	#[must_use]
+	pub fn load(&self) -> Option<Pin<Arc<T>>> {
+		let pointer = self.acquire_local()?;
+		unsafe {
+			(*pointer).ref_counter().increment();
+			self.release_local(pointer);
+			Some(Arc::pinned_from_raw(NonNull::new_unchecked(pointer)))
+		}
+	}
+
+	/// Stores `new`, dropping the previous value.
+	pub fn store(&self, new: Option<Pin<Arc<T>>>) {
+		drop(self.swap(new))
+	}
+
+	/// Stores `new` and returns the previous value.
+	#[must_use = "Use `.store(new)` to drop the previous value."]
+	pub fn swap(&self, new: Option<Pin<Arc<T>>>) -> Option<Pin<Arc<T>>> {
+		let new = Self::into_ptr(new);
+		loop {
+			// This keeps the current payload alive while its in-flight load count is transferred.
+			let current = self.load();
+			if let Ok(previous) = unsafe { self.try_replace(Self::as_ptr(current.as_ref()), new) } {
+				return previous;
+			}
+		}
+	}
+
+	/// Stores `new` iff the current value points to the same instance as `current` (or both are [`None`]),
+	/// and returns the previous value.
+	///
+	/// # Errors
+	///
+	/// Iff the current value is a different one, in which case `new` is handed back.
+	#[allow(clippy::type_complexity)]
+	pub fn compare_exchange(
+		&self,
+		current: Option<&Pin<Arc<T>>>,
+		new: Option<Pin<Arc<T>>>,
+	) -> Result<Option<Pin<Arc<T>>>, Option<Pin<Arc<T>>>> {
+		let new = Self::into_ptr(new);
+		// `current` keeps its payload alive.
+		unsafe { self.try_replace(Self::as_ptr(current), new) }
+			.map_err(|()| unsafe { Self::from_ptr(new) })
+	}
+
+	/// Repeatedly calls `update` with the current value and tries to store its result,
+	/// until no other thread has changed the value in the meantime.
+	///
+	/// Returns the previous value, which `update` was called with last.
+	pub fn rcu(
+		&self,
+		mut update: impl FnMut(&Option<Pin<Arc<T>>>) -> Option<Pin<Arc<T>>>,
+	) -> Option<Pin<Arc<T>>> {
+		let mut current = self.load();
+		loop {
+			match self.compare_exchange(current.as_ref(), update(&current)) {
+				Ok(previous) => return previous,
+				Err(_) => current = self.load(),
+			}
+		}
+	}
+
+	/// Increments the in-flight load count and returns the current untagged pointer, unless that is null.
+	fn acquire_local(&self) -> Option<*mut T> {
+		let mut current = self.tagged.load(Ordering::Relaxed);
+		loop {
+			if current.is_null() {
+				return None;
+			} else if Self::tag(current) == Self::TAG_MASK {
+				hint::spin_loop();
+				current = self.tagged.load(Ordering::Relaxed);
+			} else {
+				match self.tagged.compare_exchange_weak(
+					current,
+					current.cast::<u8>().wrapping_add(1).cast(),
+					Ordering::Acquire,
+					Ordering::Relaxed,
+				) {
+					Ok(_) => return Some(Self::untag(current)),
+					Err(actual) => current = actual,
+				}
+			}
+		}
+	}
+
+	/// Gives back one in-flight load count for `pointer`.
+	///
+	/// If a swap has transferred it to the reference-count of `pointer`'s payload instead,
+	/// then that reference is released.
+	///
+	/// # Safety
+	///
+	/// Must be called exactly once after each [`Self::acquire_local`] that returned `pointer`,
+	/// after the caller has obtained its own reference to the payload.
+	unsafe fn release_local(&self, pointer: *mut T) {
+		let mut current = self.tagged.load(Ordering::Relaxed);
+		// Counts are interchangeable, so if the same pointer was stored again, taking any of them is fine.
+		while Self::untag(current) == pointer && Self::tag(current) > 0 {
+			match self.tagged.compare_exchange_weak(
+				current,
+				current.cast::<u8>().wrapping_sub(1).cast(),
+				Ordering::Release,
+				Ordering::Relaxed,
+			) {
+				Ok(_) => return,
+				Err(actual) => current = actual,
+			}
+		}
+
+		// Not the last reference, as the caller holds another one.
+		drop(Arc::pinned_from_raw(NonNull::new_unchecked(pointer)))
+	}
+
+	/// Replaces `expected` with `new` iff the current untagged pointer is `expected`,
+	/// and transfers the in-flight load count to `expected`'s payload.
+	///
+	/// # Errors
+	///
+	/// Iff the current untagged pointer isn't `expected`.
+	///
+	/// # Safety
+	///
+	/// `expected` must be null or kept alive by the caller.
+	///
+	/// `new` must be null or a leaked [`Pin<Arc<T>>`], which is moved into `self` on success.
+	unsafe fn try_replace(
+		&self,
+		expected: *const T,
+		new: *mut T,
+	) -> Result<Option<Pin<Arc<T>>>, ()> {
+		let mut current = self.tagged.load(Ordering::Relaxed);
+		while ptr::eq(Self::untag(current), expected) {
+			match self.tagged.compare_exchange_weak(
+				current,
+				new,
+				Ordering::AcqRel,
+				Ordering::Relaxed,
+			) {
+				Ok(_) => {
+					// Unlike `expected`, which may have been derived from a reference, this keeps the provenance of the stored pointer.
+					let previous = Self::untag(current);
+					for _ in 0..Self::tag(current) {
+						(*previous).ref_counter().increment();
+					}
+					return Ok(Self::from_ptr(previous));
+				}
+				Err(actual) => current = actual,
+			}
+		}
+		Err(())
+	}
+
+	fn into_ptr(value: Option<Pin<Arc<T>>>) -> *mut T {
+		value.map_or(ptr::null_mut(), |value| Arc::leak_pinned(value).as_ptr())
+	}
+
+	fn as_ptr(value: Option<&Pin<Arc<T>>>) -> *const T {
+		value.map_or(ptr::null(), |value| ptr::addr_of!(**value))
+	}
+
+	/// # Safety
+	///
+	/// `pointer` must be null or a leaked [`Pin<Arc<T>>`].
+	unsafe fn from_ptr(pointer: *mut T) -> Option<Pin<Arc<T>>> {
+		NonNull::new(pointer).map(|pointer| Arc::pinned_from_raw(pointer))
+	}
+
+	fn tag(tagged: *mut T) -> usize {
+		tagged as usize & Self::TAG_MASK
+	}
+
+	fn untag(tagged: *mut T) -> *mut T {
+		tagged.cast::<u8>().wrapping_sub(Self::tag(tagged)).cast()
+	}
+}
+
+/// An atomically swappable [`Pin<Arc<T>>`](`Arc`) slot, for example for hot configuration.
+///
+/// This is an [`AtomicOptionArc`] that can't be empty, see there for details.
+pub struct AtomicArc<T: IntrusivelyCountable> {
+	inner: AtomicOptionArc<T>,
+}
+
+impl<T: IntrusivelyCountable> Debug for AtomicArc<T>
+where
+	T: Debug,
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.debug_tuple("AtomicArc").field(&self.load()).finish()
+	}
+}
+
+impl<T: IntrusivelyCountable> Default for AtomicArc<T>
+where
+	T: Default,
+{
+	fn default() -> Self {
+		Self::new(Arc::pin(T::default()))
+	}
+}
+
+impl<T: IntrusivelyCountable> From<Pin<Arc<T>>> for AtomicArc<T> {
+	fn from(value: Pin<Arc<T>>) -> Self {
+		Self::new(value)
+	}
+}
+
+impl<T: IntrusivelyCountable> AtomicArc<T> {
+	/// Creates a new [`AtomicArc`] containing `value`.
+	#[must_use]
+	pub fn new(value: Pin<Arc<T>>) -> Self {
+		Self {
+			inner: AtomicOptionArc::new(Some(value)),
+		}
+	}
+
+	/// Unwraps the contained value.
+	#[must_use]
+	pub fn into_inner(self) -> Pin<Arc<T>> {
+		Self::some(self.inner.into_inner())
+	}
+
+	/// Loads a new handle to the current value.
+	///
+	/// This spins while `align_of::<T>() - 1` other loads are in flight.
+	#[must_use]
+	pub fn load(&self) -> Pin<Arc<T>> {
+		Self::some(self.inner.load())
+	}
+
+	/// Stores `new`, dropping the previous value.
+	pub fn store(&self, new: Pin<Arc<T>>) {
+		self.inner.store(Some(new))
+	}
+
+	/// Stores `new` and returns the previous value.
+	#[must_use = "Use `.store(new)` to drop the previous value."]
+	pub fn swap(&self, new: Pin<Arc<T>>) -> Pin<Arc<T>> {
+		Self::some(self.inner.swap(Some(new)))
+	}
+
+	/// Stores `new` iff the current value points to the same instance as `current`,
+	/// and returns the previous value.
+	///
+	/// # Errors
+	///
+	/// Iff the current value is a different one, in which case `new` is handed back.
+	pub fn compare_exchange(
+		&self,
+		current: &Pin<Arc<T>>,
+		new: Pin<Arc<T>>,
+	) -> Result<Pin<Arc<T>>, Pin<Arc<T>>> {
+		self.inner
+			.compare_exchange(Some(current), Some(new))
+			.map(Self::some)
+			.map_err(Self::some)
+	}
+
+	/// Repeatedly calls `update` with the current value and tries to store its result,
+	/// until no other thread has changed the value in the meantime.
+	///
+	/// Returns the previous value, which `update` was called with last.
+	pub fn rcu(&self, mut update: impl FnMut(&Pin<Arc<T>>) -> Pin<Arc<T>>) -> Pin<Arc<T>> {
+		Self::some(
+			self.inner
+				.rcu(|current| Some(update(current.as_ref().unwrap_or_else(|| unreachable!())))),
+		)
+	}
+
+	fn some(value: Option<Pin<Arc<T>>>) -> Pin<Arc<T>> {
+		value.unwrap_or_else(|| unreachable!())
+	}
 }
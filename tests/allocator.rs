@@ -0,0 +1,99 @@
+#![cfg(feature = "sync")]
+
+use allocator_api2::{
+	alloc::{AllocError, Allocator, Global, Layout},
+	boxed::Box,
+};
+use core::{cell::Cell, ptr::NonNull};
+use tiptoe::{Arc, IntrusivelyCountable, TipToes};
+
+#[derive(Clone, Copy)]
+struct Counting<'a> {
+	live: &'a Cell<isize>,
+}
+
+unsafe impl Allocator for Counting<'_> {
+	fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+		self.live.set(self.live.get() + 1);
+		Global.allocate(layout)
+	}
+
+	unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+		self.live.set(self.live.get() - 1);
+		Global.deallocate(ptr, layout)
+	}
+}
+
+#[derive(Debug, Default, Clone)]
+struct Intruded {
+	value: usize,
+	counter: TipToes,
+}
+
+unsafe impl IntrusivelyCountable for Intruded {
+	type RefCounter = TipToes;
+
+	fn ref_counter(&self) -> &Self::RefCounter {
+		&self.counter
+	}
+}
+
+#[test]
+fn new_in() {
+	let live = &Cell::new(0);
+	let allocator = Counting { live };
+
+	let arc = Arc::new_in(Intruded::default(), allocator);
+	let clone = arc.clone();
+	assert_eq!(live.get(), 1);
+
+	drop(arc);
+	assert_eq!(live.get(), 1);
+	drop(clone);
+	assert_eq!(live.get(), 0);
+}
+
+#[test]
+fn pin_in_with_weak() {
+	let live = &Cell::new(0);
+	let allocator = Counting { live };
+
+	let mut arc = Arc::pin_in(Intruded::default(), allocator);
+	let weak = Arc::downgrade(&arc);
+
+	// `make_mut` clones into the same allocator.
+	let shared = arc.clone();
+	unsafe { Arc::make_mut(&mut arc).as_mut().get_unchecked_mut() }.value = 1;
+	assert_eq!(live.get(), 2);
+	assert_eq!(shared.value, 0);
+
+	drop(shared);
+	assert!(weak.upgrade().is_none());
+	assert_eq!(live.get(), 2);
+
+	drop(weak);
+	assert_eq!(live.get(), 1);
+	drop(arc);
+	assert_eq!(live.get(), 0);
+}
+
+#[test]
+fn from_box() {
+	let live = &Cell::new(0);
+	let allocator = Counting { live };
+
+	let boxed = Box::new_in(
+		Intruded {
+			value: 2,
+			counter: TipToes::new(),
+		},
+		allocator,
+	);
+	let pointer: *const Intruded = &*boxed;
+	let arc = Arc::from(boxed);
+	assert_eq!(live.get(), 1);
+	assert!(core::ptr::eq(pointer, &*arc));
+
+	assert_eq!(Arc::try_unwrap(arc).unwrap().value, 2);
+	assert_eq!(live.get(), 0);
+}
@@ -0,0 +1,68 @@
+#![cfg(feature = "sync")]
+
+use core::pin::Pin;
+use tiptoe::{Arc, ArcBorrow, AtomicTipToe, IntrusivelyCountable};
+
+#[derive(Debug, Default)]
+struct Intruded {
+	value: usize,
+	counter: AtomicTipToe,
+}
+
+unsafe impl IntrusivelyCountable for Intruded {
+	type RefCounter = AtomicTipToe;
+
+	fn ref_counter(&self) -> &Self::RefCounter {
+		&self.counter
+	}
+}
+
+fn retain(borrow: ArcBorrow<'_, Intruded>, retained: &mut Vec<Arc<Intruded>>) {
+	if borrow.value > 0 {
+		retained.push(ArcBorrow::clone_arc(borrow));
+	}
+}
+
+#[test]
+fn clone_arc() {
+	let arc = Arc::new(Intruded {
+		value: 1,
+		..Intruded::default()
+	});
+	let mut retained = vec![];
+
+	let borrow = ArcBorrow::new(&arc);
+	assert_eq!(ArcBorrow::get(borrow).value, 1);
+	retain(borrow, &mut retained);
+	retain(borrow, &mut retained);
+	assert!(Arc::ptr_eq(&arc, &retained[0]));
+
+	drop(arc);
+	drop(retained.pop());
+	let mut last = retained.pop().unwrap();
+	assert!(Arc::try_unwrap(last).is_ok());
+	last = Arc::default();
+	assert!(ArcBorrow::ptr_eq((&last).into(), ArcBorrow::new(&last)));
+}
+
+#[test]
+fn pinned() {
+	let arc = Arc::pin(Intruded::default());
+	let borrow: Pin<ArcBorrow<'_, Intruded>> = (&arc).into();
+	let mut clone = ArcBorrow::clone_pinned(borrow);
+
+	assert!(Arc::get_mut(&mut clone).is_none());
+	drop(arc);
+	assert!(Arc::get_mut(&mut clone).is_some());
+}
+
+#[test]
+fn from_inner_ref() {
+	let arc = Arc::pin(Intruded::default());
+	let inner: &Intruded = &arc;
+	let borrow = unsafe { ArcBorrow::pinned_from_inner_ref(inner) };
+	let clone = ArcBorrow::clone_pinned(borrow);
+
+	drop(arc);
+	assert_eq!(ArcBorrow::new_pinned(&clone).value, 0);
+}
@@ -0,0 +1,147 @@
+#![cfg(feature = "sync")]
+
+use core::{
+	pin::Pin,
+	sync::atomic::{AtomicUsize, Ordering},
+};
+use std::thread;
+use tiptoe::{
+	sync::{AtomicArc, AtomicOptionArc},
+	Arc, AtomicTipToe, IntrusivelyCountable,
+};
+
+static DROPPED: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Debug, Default)]
+struct Config {
+	counter: AtomicTipToe,
+	generation: usize,
+}
+
+unsafe impl IntrusivelyCountable for Config {
+	type RefCounter = AtomicTipToe;
+
+	fn ref_counter(&self) -> &Self::RefCounter {
+		&self.counter
+	}
+}
+
+impl Drop for Config {
+	fn drop(&mut self) {
+		DROPPED.fetch_add(1, Ordering::Relaxed);
+	}
+}
+
+fn config(generation: usize) -> Pin<Arc<Config>> {
+	Arc::pin(Config {
+		counter: AtomicTipToe::new(),
+		generation,
+	})
+}
+
+#[test]
+fn option_slot() {
+	let slot = AtomicOptionArc::default();
+	assert!(slot.load().is_none());
+
+	let first = config(1);
+	assert!(slot.swap(Some(first.clone())).is_none());
+	assert!(core::ptr::eq(&*slot.load().unwrap(), &*first));
+
+	let second = config(2);
+	let second = slot.compare_exchange(None, Some(second)).unwrap_err();
+	let previous = slot
+		.compare_exchange(Some(&first), second.clone())
+		.unwrap()
+		.unwrap();
+	assert!(core::ptr::eq(&*previous, &*first));
+	drop((previous, first));
+
+	slot.store(None);
+	assert!(slot.load().is_none());
+	assert!(slot.into_inner().is_none());
+
+	// The slot doesn't leak references.
+	let mut second = second.unwrap();
+	assert!(Arc::get_mut(&mut second).is_some());
+}
+
+#[test]
+fn slot() {
+	let slot = AtomicArc::new(config(0));
+	let loaded = slot.load();
+	assert_eq!(loaded.generation, 0);
+
+	let previous = slot.rcu(|current| config(current.generation + 1));
+	assert!(core::ptr::eq(&*previous, &*loaded));
+	assert_eq!(slot.load().generation, 1);
+
+	let current = slot.load();
+	let new = slot.compare_exchange(&loaded, config(5)).unwrap_err();
+	assert_eq!(new.generation, 5);
+
+	let mut current = slot.swap(current);
+	assert!(Arc::get_mut(&mut current).is_none());
+	drop(slot);
+	assert!(Arc::get_mut(&mut current).is_some());
+}
+
+#[test]
+fn concurrent() {
+	const THREADS: usize = 4;
+	const ITERATIONS: usize = if cfg!(miri) { 20 } else { 1000 };
+
+	let slot = AtomicArc::new(config(0));
+	let dropped = DROPPED.load(Ordering::Relaxed);
+
+	thread::scope(|scope| {
+		for _ in 0..THREADS {
+			scope.spawn(|| {
+				for _ in 0..ITERATIONS {
+					let loaded = slot.load();
+					assert!(loaded.generation <= THREADS * ITERATIONS);
+				}
+			});
+			scope.spawn(|| {
+				for _ in 0..ITERATIONS {
+					drop(slot.rcu(|current| config(current.generation + 1)));
+				}
+			});
+		}
+	});
+
+	let last = slot.into_inner();
+	assert_eq!(last.generation, THREADS * ITERATIONS);
+	drop(last);
+	assert!(DROPPED.load(Ordering::Relaxed) - dropped > THREADS * ITERATIONS);
+}
+
+#[test]
+fn saturated() {
+	// More concurrent loads than the tag bits below `Config`'s alignment can count.
+	const LOADERS: usize = 2 * core::mem::align_of::<Config>();
+	const ITERATIONS: usize = if cfg!(miri) { 10 } else { 1000 };
+
+	let slot = AtomicArc::new(config(0));
+
+	thread::scope(|scope| {
+		for _ in 0..LOADERS {
+			scope.spawn(|| {
+				for _ in 0..ITERATIONS {
+					let loaded = slot.load();
+					assert!(loaded.generation <= ITERATIONS);
+				}
+			});
+		}
+		scope.spawn(|| {
+			for _ in 0..ITERATIONS {
+				drop(slot.rcu(|current| config(current.generation + 1)));
+			}
+		});
+	});
+
+	// No in-flight load count was lost or leaked.
+	let mut last = slot.into_inner();
+	assert_eq!(last.generation, ITERATIONS);
+	assert!(Arc::get_mut(&mut last).is_some());
+}
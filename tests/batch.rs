@@ -0,0 +1,145 @@
+#![cfg(feature = "sync")]
+
+use std::sync::{
+	atomic::{AtomicUsize, Ordering},
+	Arc as StdArc,
+};
+use tiptoe::{Arc, AtomicTipToe, IntrusivelyCountable};
+
+#[derive(Debug)]
+struct Intruded {
+	drops: StdArc<AtomicUsize>,
+	#[cfg_attr(not(feature = "std"), allow(dead_code))]
+	child: Option<Arc<Intruded>>,
+	counter: AtomicTipToe,
+}
+
+unsafe impl IntrusivelyCountable for Intruded {
+	type RefCounter = AtomicTipToe;
+
+	fn ref_counter(&self) -> &Self::RefCounter {
+		&self.counter
+	}
+}
+
+impl Drop for Intruded {
+	fn drop(&mut self) {
+		self.drops.fetch_add(1, Ordering::Relaxed);
+		#[cfg(feature = "std")]
+		if let Some(child) = self.child.take() {
+			Arc::drop_batched(child)
+		}
+	}
+}
+
+fn intruded(drops: &StdArc<AtomicUsize>) -> Intruded {
+	with_child(drops, None)
+}
+
+fn with_child(drops: &StdArc<AtomicUsize>, child: Option<Arc<Intruded>>) -> Intruded {
+	Intruded {
+		drops: drops.clone(),
+		child,
+		counter: AtomicTipToe::new(),
+	}
+}
+
+#[test]
+fn clone_n() {
+	let drops = StdArc::new(AtomicUsize::new(0));
+	let mut arc = Arc::pin(intruded(&drops));
+
+	let clones = Arc::clone_n_pinned(&arc, 10).collect::<Vec<_>>();
+	assert_eq!(clones.len(), 10);
+	assert!(Arc::get_mut(&mut arc).is_none());
+	drop(clones);
+	assert!(Arc::get_mut(&mut arc).is_some());
+
+	assert_eq!(Arc::clone_n_pinned(&arc, 0).count(), 0);
+	assert!(Arc::get_mut(&mut arc).is_some());
+
+	drop(arc);
+	assert_eq!(drops.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+fn clone_n_dropped_early() {
+	let drops = StdArc::new(AtomicUsize::new(0));
+	let arc = Arc::new(intruded(&drops));
+
+	let mut clone_n = Arc::clone_n(&arc, 10);
+	assert_eq!(clone_n.len(), 10);
+	let taken = clone_n.by_ref().take(3).collect::<Vec<_>>();
+	assert_eq!(clone_n.len(), 7);
+	drop(clone_n);
+
+	drop(arc);
+	assert_eq!(drops.load(Ordering::Relaxed), 0);
+	drop(taken);
+	assert_eq!(drops.load(Ordering::Relaxed), 1);
+}
+
+#[cfg(feature = "std")]
+mod batched {
+	use super::{intruded, with_child};
+	use std::{
+		sync::{
+			atomic::{AtomicUsize, Ordering},
+			Arc as StdArc,
+		},
+		thread,
+	};
+	use tiptoe::{batch, Arc};
+
+	#[test]
+	fn flush() {
+		let drops = StdArc::new(AtomicUsize::new(0));
+		let mut arc = Arc::pin(intruded(&drops));
+
+		for clone in Arc::clone_n_pinned(&arc, 10) {
+			Arc::drop_batched_pinned(clone);
+		}
+		assert!(Arc::get_mut(&mut arc).is_none());
+		batch::flush();
+		assert!(Arc::get_mut(&mut arc).is_some());
+
+		Arc::drop_batched_pinned(arc);
+		assert_eq!(drops.load(Ordering::Relaxed), 0);
+		batch::flush();
+		assert_eq!(drops.load(Ordering::Relaxed), 1);
+	}
+
+	#[test]
+	fn full() {
+		let drops = StdArc::new(AtomicUsize::new(0));
+
+		for _ in 1..batch::CAPACITY {
+			Arc::drop_batched(Arc::new(intruded(&drops)));
+		}
+		assert_eq!(drops.load(Ordering::Relaxed), 0);
+
+		Arc::drop_batched(Arc::new(intruded(&drops)));
+		assert_eq!(drops.load(Ordering::Relaxed), batch::CAPACITY);
+	}
+
+	#[test]
+	fn reentrant() {
+		let drops = StdArc::new(AtomicUsize::new(0));
+		let arc = Arc::new(with_child(&drops, Some(Arc::new(intruded(&drops)))));
+
+		Arc::drop_batched(arc);
+		batch::flush();
+		assert_eq!(drops.load(Ordering::Relaxed), 2);
+	}
+
+	#[test]
+	fn thread_exit() {
+		let drops = StdArc::new(AtomicUsize::new(0));
+		let arc = Arc::new(intruded(&drops));
+
+		thread::scope(|scope| {
+			scope.spawn(|| Arc::drop_batched(arc)).join().unwrap();
+		});
+		assert_eq!(drops.load(Ordering::Relaxed), 1);
+	}
+}
@@ -0,0 +1,231 @@
+#![cfg(feature = "std")]
+
+use std::{
+	pin::Pin,
+	sync::{
+		atomic::{AtomicBool, Ordering},
+		mpsc, Arc as StdArc, Barrier,
+	},
+	thread,
+};
+use tiptoe::{Arc, BiasedTipToe, IntrusivelyCountable};
+
+#[derive(Debug)]
+struct Intruded {
+	dropped: StdArc<AtomicBool>,
+	counter: BiasedTipToe,
+}
+
+unsafe impl IntrusivelyCountable for Intruded {
+	type RefCounter = BiasedTipToe;
+
+	fn ref_counter(&self) -> &Self::RefCounter {
+		&self.counter
+	}
+}
+
+impl Drop for Intruded {
+	fn drop(&mut self) {
+		assert!(!self.dropped.swap(true, Ordering::Relaxed));
+	}
+}
+
+fn payload() -> (Intruded, StdArc<AtomicBool>) {
+	let dropped = StdArc::new(AtomicBool::new(false));
+	let payload = Intruded {
+		dropped: dropped.clone(),
+		counter: BiasedTipToe::new(),
+	};
+	(payload, dropped)
+}
+
+fn intruded() -> (Pin<Arc<Intruded>>, StdArc<AtomicBool>) {
+	let (payload, dropped) = payload();
+	(Arc::pin(payload), dropped)
+}
+
+#[test]
+fn owner_thread() {
+	let (arc, dropped) = intruded();
+	let clones = (0..10).map(|_| arc.clone()).collect::<Vec<_>>();
+	drop(arc);
+	drop(clones);
+	assert!(dropped.load(Ordering::Relaxed));
+}
+
+#[test]
+fn get_mut_merges() {
+	let (mut arc, dropped) = intruded();
+	let clone = arc.clone();
+	assert!(Arc::get_mut(&mut arc).is_none());
+
+	drop(clone);
+	assert!(Arc::get_mut(&mut arc).is_some());
+
+	// Counting continues after merging.
+	let clone = arc.clone();
+	assert!(Arc::get_mut(&mut arc).is_none());
+	drop(clone);
+	assert!(Arc::get_mut(&mut arc).is_some());
+
+	drop(arc);
+	assert!(dropped.load(Ordering::Relaxed));
+}
+
+#[test]
+fn owner_decrements_past_its_share() {
+	let (arc, dropped) = intruded();
+	let clones = thread::scope(|scope| {
+		scope
+			.spawn(|| Arc::clone_n_pinned(&arc, 3).collect::<Vec<_>>())
+			.join()
+			.unwrap()
+	});
+
+	// Gives up all three at once.
+	for clone in clones {
+		Arc::drop_batched_pinned(clone);
+	}
+	tiptoe::batch::flush();
+	assert!(!dropped.load(Ordering::Relaxed));
+
+	drop(arc);
+	assert!(dropped.load(Ordering::Relaxed));
+}
+
+#[test]
+fn other_thread_drops_shared_count() {
+	let (arc, dropped) = intruded();
+	let clone = arc.clone();
+	let clone = thread::spawn(move || {
+		let clones = (0..10).map(|_| clone.clone()).collect::<Vec<_>>();
+		drop(clones);
+		clone
+	})
+	.join()
+	.unwrap();
+
+	drop(arc);
+	assert!(!dropped.load(Ordering::Relaxed));
+	drop(clone);
+	assert!(dropped.load(Ordering::Relaxed));
+}
+
+#[test]
+fn owner_share_merged_on_owner_drop() {
+	let (arc, dropped) = intruded();
+	let clone = thread::scope(|scope| scope.spawn(|| arc.clone()).join().unwrap());
+	drop(arc);
+	assert!(!dropped.load(Ordering::Relaxed));
+
+	thread::spawn(move || drop(clone)).join().unwrap();
+	assert!(dropped.load(Ordering::Relaxed));
+}
+
+#[test]
+fn hand_off_to_owner() {
+	let (arc, dropped) = intruded();
+	thread::spawn(move || drop(arc)).join().unwrap();
+	assert!(!dropped.load(Ordering::Relaxed));
+
+	BiasedTipToe::drop_deferred();
+	assert!(dropped.load(Ordering::Relaxed));
+}
+
+#[test]
+fn hand_off_on_owner_clone() {
+	let (arc, dropped) = intruded();
+	thread::spawn(move || drop(arc)).join().unwrap();
+	assert!(!dropped.load(Ordering::Relaxed));
+
+	let (other, _) = intruded();
+	drop(other.clone());
+	assert!(dropped.load(Ordering::Relaxed));
+}
+
+#[test]
+fn hand_off_at_owner_exit() {
+	let barrier = &Barrier::new(2);
+	let (sender, receiver) = mpsc::channel();
+
+	let dropped = thread::scope(|scope| {
+		let owner = scope.spawn(move || {
+			let (arc, dropped) = intruded();
+			sender.send(arc).unwrap();
+			barrier.wait();
+			dropped
+		});
+
+		drop(receiver.recv().unwrap());
+		barrier.wait();
+		owner.join().unwrap()
+	});
+	assert!(dropped.load(Ordering::Relaxed));
+}
+
+#[test]
+fn owner_exited() {
+	let (arc, dropped) = thread::spawn(intruded).join().unwrap();
+	let clone = arc.clone();
+	drop(arc);
+	assert!(!dropped.load(Ordering::Relaxed));
+
+	let mut clone = clone;
+	assert!(Arc::get_mut(&mut clone).is_some());
+	drop(clone);
+	assert!(dropped.load(Ordering::Relaxed));
+}
+
+#[test]
+fn into_inner_hands_off() {
+	let (payload, dropped) = payload();
+	let arc = Arc::new(payload);
+	let clone = arc.clone();
+
+	let inner = thread::spawn(move || Arc::into_inner(clone).is_some())
+		.join()
+		.unwrap();
+	assert!(!inner);
+
+	// Dropping a handle on the owner thread also drops the handles deferred to it.
+	drop(arc);
+	assert!(dropped.load(Ordering::Relaxed));
+}
+
+#[test]
+fn batched_hand_off() {
+	let (arc, dropped) = intruded();
+	let clones = Arc::clone_n_pinned(&arc, 3).collect::<Vec<_>>();
+
+	thread::spawn(move || {
+		for clone in clones {
+			Arc::drop_batched_pinned(clone);
+		}
+		tiptoe::batch::flush();
+	})
+	.join()
+	.unwrap();
+
+	// Dropping a handle on the owner thread also drops the handles deferred to it.
+	drop(arc);
+	assert!(dropped.load(Ordering::Relaxed));
+}
+
+#[test]
+fn batched_on_owner_thread() {
+	let (arc, dropped) = intruded();
+	let clones = thread::scope(|scope| {
+		scope
+			.spawn(|| Arc::clone_n_pinned(&arc, 5).collect::<Vec<_>>())
+			.join()
+			.unwrap()
+	});
+
+	for clone in clones {
+		Arc::drop_batched_pinned(clone);
+	}
+	Arc::drop_batched_pinned(arc);
+	assert!(!dropped.load(Ordering::Relaxed));
+	tiptoe::batch::flush();
+	assert!(dropped.load(Ordering::Relaxed));
+}
@@ -0,0 +1,140 @@
+use std::{cell::Cell, panic, rc::Rc as StdRc};
+use tiptoe::{counted::Counted, Rc};
+
+#[test]
+fn slices() {
+	let from_vec: Rc<Counted<[u16]>> = vec![1, 2, 3].into();
+	let from_slice: Rc<Counted<[u16]>> = (&[1, 2, 3][..]).into();
+	let from_iter: Rc<Counted<[u16]>> = (1..=3).collect();
+	let from_filter: Rc<Counted<[u16]>> = (0..10).filter(|i| (1..=3).contains(i)).collect();
+
+	assert_eq!(&**from_vec, &[1, 2, 3]);
+	assert_eq!(from_vec, from_slice);
+	assert_eq!(from_vec, from_iter);
+	assert_eq!(from_vec, from_filter);
+}
+
+#[test]
+fn strs() {
+	let string: Rc<Counted<str>> = "Hello!".into();
+	let clone = string.clone();
+	drop(string);
+	assert_eq!(&**clone, "Hello!");
+	assert_eq!(clone.to_string(), "Hello!");
+}
+
+#[test]
+fn zero_sized() {
+	let empty: Rc<Counted<[u64]>> = Vec::new().into();
+	let units: Rc<Counted<[()]>> = vec![(); 5].into();
+	assert!(empty.is_empty());
+	assert_eq!(units.len(), 5);
+}
+
+#[test]
+fn drops_items() {
+	let counter = StdRc::new(());
+	let slice: Rc<Counted<[StdRc<()>]>> = vec![counter.clone(), counter.clone()].into();
+	assert_eq!(StdRc::strong_count(&counter), 3);
+	drop(slice);
+	assert_eq!(StdRc::strong_count(&counter), 1);
+}
+
+/// Claims an exact length that differs from the number of items it yields.
+struct Lying<'a> {
+	remaining: usize,
+	announced: usize,
+	dropped: &'a Cell<usize>,
+}
+
+impl<'a> Lying<'a> {
+	fn new(remaining: usize, announced: usize, dropped: &'a Cell<usize>) -> Self {
+		Self {
+			remaining,
+			announced,
+			dropped,
+		}
+	}
+}
+
+struct Droppable<'a>(&'a Cell<usize>);
+
+impl Drop for Droppable<'_> {
+	fn drop(&mut self) {
+		self.0.set(self.0.get() + 1)
+	}
+}
+
+impl<'a> Iterator for Lying<'a> {
+	type Item = Droppable<'a>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		(self.remaining > 0).then(|| {
+			self.remaining -= 1;
+			self.announced = self.announced.saturating_sub(1);
+			Droppable(self.dropped)
+		})
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(self.announced, Some(self.announced))
+	}
+}
+
+impl ExactSizeIterator for Lying<'_> {}
+
+#[test]
+fn short_exact_iterator_panics_cleanly() {
+	let dropped = Cell::new(0);
+	let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+		Counted::<[Droppable<'_>]>::try_from_exact_iter(Lying::new(2, 3, &dropped))
+	}));
+	assert!(result.is_err());
+	assert_eq!(dropped.get(), 2);
+}
+
+#[test]
+fn collect_fewer_than_announced() {
+	let dropped = Cell::new(0);
+	let slice = Lying::new(2, 3, &dropped).collect::<Rc<Counted<[Droppable<'_>]>>>();
+	assert_eq!(slice.len(), 2);
+	drop(slice);
+	assert_eq!(dropped.get(), 2);
+}
+
+#[test]
+fn collect_more_than_announced() {
+	let dropped = Cell::new(0);
+	let slice = Lying::new(3, 2, &dropped).collect::<Rc<Counted<[Droppable<'_>]>>>();
+	assert_eq!(slice.len(), 3);
+	drop(slice);
+	assert_eq!(dropped.get(), 3);
+}
+
+#[test]
+fn collect_exact_panicking() {
+	let dropped = Cell::new(0);
+	let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+		(0..3)
+			.map(|i| {
+				assert!(i < 2, "third item");
+				Droppable(&dropped)
+			})
+			.collect::<Rc<Counted<[Droppable<'_>]>>>()
+	}));
+	assert!(result.is_err());
+	assert_eq!(dropped.get(), 2);
+}
+
+#[cfg(feature = "sync")]
+#[test]
+fn arc() {
+	use tiptoe::{Arc, AtomicTipToe};
+
+	let slice: Arc<Counted<[u8], AtomicTipToe>> = b"bytes"[..].into();
+	let string: Arc<Counted<str, AtomicTipToe>> = "bytes".into();
+	assert_eq!(&**slice, string.as_bytes());
+
+	let sized = Arc::new(Counted::<_, AtomicTipToe>::new(1));
+	assert_eq!(Counted::into_inner(Arc::try_unwrap(sized).unwrap()), 1);
+}
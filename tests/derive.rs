@@ -0,0 +1,69 @@
+#![cfg(feature = "derive")]
+
+use core::marker::PhantomData;
+use tiptoe::{IntrusivelyCountable, Rc, TipToe, TipToes};
+
+#[derive(IntrusivelyCountable)]
+struct Named<'a, T: ?Sized> {
+	#[ref_counter]
+	counter: TipToe,
+	_phantom: PhantomData<&'a T>,
+}
+
+#[derive(IntrusivelyCountable)]
+struct Tuple(usize, #[ref_counter] TipToes);
+
+#[derive(IntrusivelyCountable)]
+enum Enum<T>
+where
+	T: Copy,
+{
+	Tuple(T, #[ref_counter] TipToe),
+	Struct {
+		#[ref_counter]
+		counter: TipToe,
+	},
+}
+
+#[test]
+fn named() {
+	let named = Named::<str> {
+		counter: TipToe::new(),
+		_phantom: PhantomData,
+	};
+	assert!(core::ptr::eq(named.ref_counter(), &named.counter));
+
+	let mut rc = Rc::pin(named);
+	let clone = rc.clone();
+	assert!(Rc::get_mut(&mut rc).is_none());
+	drop(clone);
+	assert!(Rc::get_mut(&mut rc).is_some());
+}
+
+#[test]
+fn tuple() {
+	let tuple = Tuple(1, TipToes::new());
+	assert_eq!(tuple.0, 1);
+	assert!(core::ptr::eq(tuple.ref_counter(), &tuple.1));
+
+	let rc = Rc::pin(tuple);
+	let weak = Rc::downgrade(&rc);
+	assert!(weak.upgrade().is_some());
+}
+
+#[test]
+fn enum_variants() {
+	let tuple = Enum::Tuple(1, TipToe::new());
+	match &tuple {
+		Enum::Tuple(_, counter) => assert!(core::ptr::eq(tuple.ref_counter(), counter)),
+		Enum::Struct { .. } => unreachable!(),
+	}
+
+	let r#struct = Enum::<()>::Struct {
+		counter: TipToe::new(),
+	};
+	match &r#struct {
+		Enum::Struct { counter } => assert!(core::ptr::eq(r#struct.ref_counter(), counter)),
+		Enum::Tuple(..) => unreachable!(),
+	}
+}
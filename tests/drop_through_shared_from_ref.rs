@@ -1,15 +1,15 @@
 #![cfg(feature = "sync")]
 
-use tiptoe::{Arc, IntrusivelyCountable, TipToe};
+use tiptoe::{Arc, AtomicTipToe, IntrusivelyCountable};
 
 #[derive(Default)]
 struct Intruded {
 	_nontrivial: Box<usize>,
-	counter: TipToe,
+	counter: AtomicTipToe,
 }
 
 unsafe impl IntrusivelyCountable for Intruded {
-	type RefCounter = TipToe;
+	type RefCounter = AtomicTipToe;
 
 	fn ref_counter(&self) -> &Self::RefCounter {
 		&self.counter
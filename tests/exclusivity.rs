@@ -0,0 +1,65 @@
+#![cfg(feature = "sync")]
+
+use std::{pin::Pin, thread};
+use tiptoe::{Arc, AtomicTipToes, IntrusivelyCountable};
+
+#[derive(Debug, Default)]
+struct Intruded {
+	value: usize,
+	counter: AtomicTipToes,
+}
+
+unsafe impl IntrusivelyCountable for Intruded {
+	type RefCounter = AtomicTipToes;
+
+	fn ref_counter(&self) -> &Self::RefCounter {
+		&self.counter
+	}
+}
+
+#[test]
+fn get_mut_across_threads() {
+	let mut arc = Arc::pin(Intruded::default());
+	let weak = Arc::downgrade(&arc);
+
+	let clone = arc.clone();
+	assert!(Arc::get_mut(&mut arc).is_none());
+	thread::spawn(move || drop(clone)).join().unwrap();
+
+	{
+		let mut exclusive = Arc::get_mut(&mut arc).unwrap();
+		unsafe { exclusive.as_mut().get_unchecked_mut() }.value = 1;
+
+		// Upgrading during exclusivity fails instead of racing the borrow.
+		let weak = weak.clone();
+		assert!(thread::spawn(move || weak.upgrade().is_none())
+			.join()
+			.unwrap());
+	}
+
+	let upgraded = thread::spawn(move || weak.upgrade().map(|arc| arc.value))
+		.join()
+		.unwrap();
+	assert_eq!(upgraded, Some(1));
+	assert!(Arc::get_mut(&mut arc).is_some());
+}
+
+#[test]
+fn try_unwrap_with_weak() {
+	let arc = Arc::pin(Intruded {
+		value: 2,
+		counter: AtomicTipToes::new(),
+	});
+	let weak = Arc::downgrade(&arc);
+
+	// `Intruded` isn't address-sensitive beyond its reference counter.
+	let unwrapped = Arc::try_unwrap(unsafe { Pin::into_inner_unchecked(arc) }).unwrap();
+	assert_eq!(unwrapped.value, 2);
+	assert!(thread::spawn(move || weak.upgrade().is_none())
+		.join()
+		.unwrap());
+
+	// The unwrapped counter is reset and can be reused.
+	let mut arc = Arc::pin(unwrapped);
+	assert!(Arc::get_mut(&mut arc).is_some());
+}
@@ -0,0 +1,178 @@
+use core::pin::Pin;
+use tiptoe::{IntrusivelyCountable, Rc, TipToe, TipToes};
+
+#[derive(Debug, Default)]
+struct Intruded {
+	value: usize,
+	counter: TipToe,
+}
+
+unsafe impl IntrusivelyCountable for Intruded {
+	type RefCounter = TipToe;
+
+	fn ref_counter(&self) -> &Self::RefCounter {
+		&self.counter
+	}
+}
+
+#[derive(Debug, Default)]
+struct WeakIntruded {
+	counter: TipToes,
+}
+
+unsafe impl IntrusivelyCountable for WeakIntruded {
+	type RefCounter = TipToes;
+
+	fn ref_counter(&self) -> &Self::RefCounter {
+		&self.counter
+	}
+}
+
+#[test]
+fn try_into_box() {
+	let rc = Rc::new(Intruded {
+		value: 1,
+		..Intruded::default()
+	});
+	let address: *const Intruded = &*rc;
+
+	let clone = rc.clone();
+	let rc = Rc::try_into_box(rc).unwrap_err();
+	drop(clone);
+
+	let box_ = Rc::try_into_box(rc).unwrap();
+	assert!(core::ptr::eq(address, &*box_));
+	assert_eq!(box_.value, 1);
+
+	// The reference-count was reset.
+	let rc = Rc::from(box_);
+	assert_eq!(Rc::try_unwrap(rc).unwrap().value, 1);
+}
+
+#[test]
+fn try_into_pin_box() {
+	let rc = Rc::pin(WeakIntruded::default());
+	let address: *const WeakIntruded = &*rc;
+
+	let weak = Rc::downgrade(&rc);
+	let rc = Rc::try_into_pin_box(rc).unwrap_err();
+	drop(weak);
+
+	let box_: Pin<Box<WeakIntruded>> = Rc::try_into_pin_box(rc).unwrap();
+	assert!(core::ptr::eq(address, &*box_));
+
+	let rc = unsafe { Pin::new_unchecked(Rc::from(Pin::into_inner_unchecked(box_))) };
+	let weak = Rc::downgrade(&rc);
+	drop(rc);
+	assert!(weak.upgrade().is_none());
+}
+
+#[cfg(feature = "sync")]
+mod sync {
+	use core::pin::Pin;
+	use std::{
+		sync::{
+			atomic::{AtomicBool, Ordering},
+			Arc as StdArc,
+		},
+		thread,
+	};
+	use tiptoe::{Arc, AtomicTipToe, AtomicTipToes, IntrusivelyCountable};
+
+	#[derive(Debug, Default)]
+	struct Intruded {
+		value: usize,
+		counter: AtomicTipToe,
+	}
+
+	unsafe impl IntrusivelyCountable for Intruded {
+		type RefCounter = AtomicTipToe;
+
+		fn ref_counter(&self) -> &Self::RefCounter {
+			&self.counter
+		}
+	}
+
+	#[derive(Debug, Default)]
+	struct WeakIntruded {
+		counter: AtomicTipToes,
+	}
+
+	unsafe impl IntrusivelyCountable for WeakIntruded {
+		type RefCounter = AtomicTipToes;
+
+		fn ref_counter(&self) -> &Self::RefCounter {
+			&self.counter
+		}
+	}
+
+	#[test]
+	fn try_into_box() {
+		let arc = Arc::new(Intruded {
+			value: 1,
+			..Intruded::default()
+		});
+		let address: *const Intruded = &*arc;
+
+		let clone = arc.clone();
+		let arc = Arc::try_into_box(arc).unwrap_err();
+		drop(clone);
+
+		let box_ = Arc::try_into_box(arc).unwrap();
+		assert!(core::ptr::eq(address, &*box_));
+		assert_eq!(box_.value, 1);
+
+		// The reference-count was reset.
+		let arc = Arc::from(box_);
+		assert_eq!(Arc::try_unwrap(arc).unwrap().value, 1);
+	}
+
+	#[test]
+	fn try_into_pin_box() {
+		let arc = Arc::pin(WeakIntruded::default());
+		let address: *const WeakIntruded = &*arc;
+
+		let weak = Arc::downgrade(&arc);
+		let arc = Arc::try_into_pin_box(arc).unwrap_err();
+		drop(weak);
+
+		let box_: Pin<Box<WeakIntruded>> = Arc::try_into_pin_box(arc).unwrap();
+		assert!(core::ptr::eq(address, &*box_));
+
+		let arc = unsafe { Pin::new_unchecked(Arc::from(Pin::into_inner_unchecked(box_))) };
+		let weak = Arc::downgrade(&arc);
+		drop(arc);
+		assert!(weak.upgrade().is_none());
+	}
+
+	#[test]
+	fn upgrade_during_try_into_box() {
+		const ITERATIONS: usize = if cfg!(miri) { 100 } else { 1_000_000 };
+
+		let mut arc = Arc::pin(WeakIntruded::default());
+		let done = StdArc::new(AtomicBool::new(false));
+
+		let upgrader = thread::spawn({
+			let mut strong = arc.clone();
+			let done = done.clone();
+			move || {
+				for _ in 0..ITERATIONS {
+					let weak = Arc::downgrade(&strong);
+					drop(strong);
+					// The main thread's handle is still alive.
+					strong = weak.upgrade().unwrap();
+				}
+				done.store(true, Ordering::Relaxed);
+				strong
+			}
+		});
+
+		while !done.load(Ordering::Relaxed) {
+			arc = match Arc::try_into_pin_box(arc) {
+				Ok(_) => panic!("The upgrader's handle is still alive."),
+				Err(arc) => arc,
+			};
+		}
+		drop(upgrader.join().unwrap());
+	}
+}
@@ -0,0 +1,112 @@
+use tiptoe::{IntrusivelyCountable, Rc, TipToe};
+
+#[derive(Debug, Default, Clone)]
+struct Intruded {
+	value: usize,
+	counter: TipToe,
+}
+
+unsafe impl IntrusivelyCountable for Intruded {
+	type RefCounter = TipToe;
+
+	fn ref_counter(&self) -> &Self::RefCounter {
+		&self.counter
+	}
+}
+
+#[test]
+fn into_inner() {
+	let rc = Rc::new(Intruded {
+		value: 1,
+		..Intruded::default()
+	});
+	let clone = rc.clone();
+
+	assert!(Rc::into_inner(rc).is_none());
+	let value = Rc::into_inner(clone).unwrap();
+	assert_eq!(value.value, 1);
+
+	// The reference-count was reset.
+	assert_eq!(Rc::try_unwrap(Rc::new(value)).unwrap().value, 1);
+}
+
+#[test]
+fn unwrap_or_clone() {
+	let rc = Rc::new(Intruded {
+		value: 2,
+		..Intruded::default()
+	});
+	let clone = rc.clone();
+
+	let cloned = Rc::unwrap_or_clone(rc);
+	assert_eq!(cloned.value, 2);
+	assert_eq!(Rc::try_unwrap(Rc::new(cloned)).unwrap().value, 2);
+
+	let unwrapped = Rc::unwrap_or_clone(clone);
+	assert_eq!(Rc::try_unwrap(Rc::new(unwrapped)).unwrap().value, 2);
+}
+
+#[cfg(feature = "sync")]
+mod sync {
+	use std::{sync::Barrier, thread};
+	use tiptoe::{Arc, AtomicTipToe, IntrusivelyCountable};
+
+	#[derive(Debug, Default, Clone)]
+	struct Intruded {
+		value: usize,
+		counter: AtomicTipToe,
+	}
+
+	unsafe impl IntrusivelyCountable for Intruded {
+		type RefCounter = AtomicTipToe;
+
+		fn ref_counter(&self) -> &Self::RefCounter {
+			&self.counter
+		}
+	}
+
+	#[test]
+	fn into_inner_race() {
+		const THREADS: usize = 4;
+
+		let arc = Arc::new(Intruded {
+			value: 3,
+			..Intruded::default()
+		});
+		let mut handles = (1..THREADS).map(|_| arc.clone()).collect::<Vec<_>>();
+		handles.push(arc);
+		let barrier = &Barrier::new(THREADS);
+
+		let received = thread::scope(|scope| {
+			handles
+				.into_iter()
+				.map(|arc| {
+					scope.spawn(move || {
+						barrier.wait();
+						Arc::into_inner(arc)
+					})
+				})
+				.collect::<Vec<_>>()
+				.into_iter()
+				.filter_map(|thread| thread.join().unwrap())
+				.collect::<Vec<_>>()
+		});
+		assert_eq!(received.len(), 1);
+		assert_eq!(received[0].value, 3);
+	}
+
+	#[test]
+	fn unwrap_or_clone() {
+		let arc = Arc::new(Intruded {
+			value: 4,
+			..Intruded::default()
+		});
+		let clone = arc.clone();
+
+		let cloned = Arc::unwrap_or_clone(arc);
+		assert_eq!(cloned.value, 4);
+
+		let unwrapped = Arc::unwrap_or_clone(clone);
+		assert_eq!(Arc::try_unwrap(Arc::new(unwrapped)).unwrap().value, 4);
+	}
+}
@@ -0,0 +1,89 @@
+use core::pin::Pin;
+use tiptoe::{unsize, IntrusiveAny, IntrusivelyCountable, Rc, TipToe};
+
+struct A {
+	counter: TipToe,
+	value: usize,
+}
+
+unsafe impl IntrusivelyCountable for A {
+	type RefCounter = TipToe;
+
+	fn ref_counter(&self) -> &Self::RefCounter {
+		&self.counter
+	}
+}
+
+struct B {
+	counter: TipToe,
+}
+
+unsafe impl IntrusivelyCountable for B {
+	type RefCounter = TipToe;
+
+	fn ref_counter(&self) -> &Self::RefCounter {
+		&self.counter
+	}
+}
+
+#[test]
+fn downcast() {
+	let any: Rc<dyn IntrusiveAny> = unsize!(Rc::new(A {
+		counter: TipToe::new(),
+		value: 1,
+	}) => dyn IntrusiveAny);
+	assert!(any.is::<A>());
+	assert!(!any.is::<B>());
+	assert!(any.downcast_ref::<B>().is_none());
+
+	let any = Rc::downcast::<B>(any).err().unwrap();
+	let a = Rc::downcast::<A>(any).ok().unwrap();
+	assert_eq!(a.value, 1);
+}
+
+#[test]
+fn downcast_pinned() {
+	let any: Pin<Rc<dyn IntrusiveAny + Send>> = unsize!(Rc::pin(B {
+		counter: TipToe::new(),
+	}) => dyn IntrusiveAny + Send);
+	let clone = any.clone();
+	assert!(any.downcast_ref::<B>().is_some());
+
+	let any = Rc::downcast_pinned::<A>(any).err().unwrap();
+	let mut b = Rc::downcast_pinned::<B>(any).ok().unwrap();
+	assert!(Rc::get_mut(&mut b).is_none());
+	drop(clone);
+	assert!(Rc::get_mut(&mut b).is_some());
+}
+
+#[cfg(feature = "sync")]
+#[test]
+fn arc() {
+	use tiptoe::{Arc, AtomicTipToe};
+
+	struct C {
+		counter: AtomicTipToe,
+		value: usize,
+	}
+
+	unsafe impl IntrusivelyCountable for C {
+		type RefCounter = AtomicTipToe;
+
+		fn ref_counter(&self) -> &Self::RefCounter {
+			&self.counter
+		}
+	}
+
+	let any: Pin<Arc<dyn IntrusiveAny<AtomicTipToe> + Send + Sync>> = unsize!(Arc::pin(C {
+		counter: AtomicTipToe::new(),
+		value: 2,
+	}) => dyn IntrusiveAny<AtomicTipToe> + Send + Sync);
+
+	let clone = any.clone();
+	std::thread::spawn(move || assert_eq!(clone.downcast_ref::<C>().unwrap().value, 2))
+		.join()
+		.unwrap();
+
+	let c = Arc::downcast_pinned::<C>(any).ok().unwrap();
+	assert_eq!(c.value, 2);
+}
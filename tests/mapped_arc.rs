@@ -0,0 +1,75 @@
+#![cfg(feature = "sync")]
+
+use core::{marker::PhantomPinned, pin::Pin};
+use tiptoe::{Arc, AtomicTipToes, IntrusivelyCountable, MappedArc};
+
+#[derive(Debug, Default)]
+struct Parent {
+	counter: AtomicTipToes,
+	name: String,
+	child: Child,
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct Child {
+	value: usize,
+	_pinned: PhantomPinned,
+}
+
+unsafe impl IntrusivelyCountable for Parent {
+	type RefCounter = AtomicTipToes;
+
+	fn ref_counter(&self) -> &Self::RefCounter {
+		&self.counter
+	}
+}
+
+impl Parent {
+	fn child(self: Pin<&Self>) -> Pin<&Child> {
+		unsafe { self.map_unchecked(|parent| &parent.child) }
+	}
+}
+
+#[test]
+fn keeps_owner_alive() {
+	let mut parent = Arc::pin(Parent {
+		name: "parent".to_string(),
+		..Parent::default()
+	});
+	let weak = Arc::downgrade(&parent);
+
+	let child = MappedArc::new_pinned(parent.clone(), Parent::child);
+	assert!(Arc::get_mut(&mut parent).is_none());
+	drop(parent);
+
+	let value = MappedArc::map_pinned(child.clone(), |child| Pin::new(&Pin::get_ref(child).value));
+	drop(child);
+	assert_eq!(*value, 0);
+	assert_eq!(MappedArc::owner_pinned(&value).name, "parent");
+
+	let mut parent = weak.upgrade().unwrap();
+	drop(value);
+	assert!(Arc::get_mut(&mut parent).is_some());
+}
+
+#[test]
+fn compose_and_compare() {
+	let parent = Arc::new(Parent {
+		name: "parent".to_string(),
+		..Parent::default()
+	});
+	let other = Arc::new(Parent::default());
+
+	let name = MappedArc::new(parent.clone(), |parent| parent.name.as_str());
+	let prefix = MappedArc::map(name.clone(), |name| &name[..3]);
+	assert_eq!(&*prefix, "par");
+	assert!(MappedArc::ptr_eq(
+		&name,
+		&MappedArc::map(name.clone(), |name| name)
+	));
+
+	let child = MappedArc::new(parent, |parent| &parent.child);
+	let other_child = MappedArc::new(other, |other| &other.child);
+	assert_eq!(child, other_child);
+	assert!(!MappedArc::ptr_eq(&child, &other_child));
+}
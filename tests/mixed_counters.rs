@@ -0,0 +1,45 @@
+#![cfg(feature = "sync")]
+
+use std::thread;
+use tiptoe::{Arc, AtomicTipToe, IntrusivelyCountable, Rc, TipToe};
+
+#[derive(Default)]
+struct Local {
+	counter: TipToe,
+}
+
+unsafe impl IntrusivelyCountable for Local {
+	type RefCounter = TipToe;
+
+	fn ref_counter(&self) -> &Self::RefCounter {
+		&self.counter
+	}
+}
+
+#[derive(Default)]
+struct Shared {
+	counter: AtomicTipToe,
+}
+
+unsafe impl IntrusivelyCountable for Shared {
+	type RefCounter = AtomicTipToe;
+
+	fn ref_counter(&self) -> &Self::RefCounter {
+		&self.counter
+	}
+}
+
+#[test]
+fn mixed_counters() {
+	let mut local = Rc::pin(Local::default());
+	let local_2 = local.clone();
+
+	let mut shared = Arc::pin(Shared::default());
+	let shared_2 = shared.clone();
+	thread::spawn(move || drop(shared_2)).join().unwrap();
+	assert!(Arc::get_mut(&mut shared).is_some());
+
+	assert!(Rc::get_mut(&mut local).is_none());
+	drop(local_2);
+	assert!(Rc::get_mut(&mut local).is_some());
+}
@@ -0,0 +1,117 @@
+use core::{mem::MaybeUninit, pin::Pin, ptr};
+use std::panic::{self, AssertUnwindSafe};
+use tiptoe::{IntrusivelyCountable, Rc, TipToe};
+
+#[derive(Debug)]
+struct Intruded {
+	counter: TipToe,
+	value: usize,
+}
+
+unsafe impl IntrusivelyCountable for Intruded {
+	type RefCounter = TipToe;
+
+	fn ref_counter(&self) -> &Self::RefCounter {
+		&self.counter
+	}
+}
+
+fn write(uninit: Pin<&mut MaybeUninit<Intruded>>, value: usize) {
+	let intruded = unsafe { uninit.get_unchecked_mut() }.as_mut_ptr();
+	unsafe {
+		ptr::addr_of_mut!((*intruded).counter).write(TipToe::new());
+		ptr::addr_of_mut!((*intruded).value).write(value);
+	}
+}
+
+#[test]
+fn pin_init() {
+	let mut rc = unsafe { Rc::pin_init(|uninit| write(uninit, 1)) };
+	assert_eq!(rc.value, 1);
+
+	// The reference-count is exactly 1.
+	assert!(Rc::get_mut(&mut rc).is_some());
+}
+
+#[test]
+fn try_pin_init_error() {
+	let result = unsafe { Rc::<Intruded>::try_pin_init(|_| Err("failed")) };
+	assert_eq!(result.err(), Some("failed"));
+}
+
+#[test]
+fn try_pin_init_panic() {
+	let result = panic::catch_unwind(AssertUnwindSafe(|| unsafe {
+		Rc::<Intruded>::try_pin_init(|_| -> Result<(), ()> { panic!() })
+	}));
+	assert!(result.is_err());
+}
+
+#[cfg(feature = "sync")]
+mod sync {
+	use allocator_api2::alloc::Global;
+	use core::{mem::MaybeUninit, pin::Pin, ptr};
+	use std::panic::{self, AssertUnwindSafe};
+	use tiptoe::{Arc, AtomicTipToe, IntrusivelyCountable};
+
+	#[derive(Debug)]
+	struct Intruded {
+		counter: AtomicTipToe,
+		value: usize,
+	}
+
+	unsafe impl IntrusivelyCountable for Intruded {
+		type RefCounter = AtomicTipToe;
+
+		fn ref_counter(&self) -> &Self::RefCounter {
+			&self.counter
+		}
+	}
+
+	fn write(uninit: Pin<&mut MaybeUninit<Intruded>>, value: usize) {
+		let intruded = unsafe { uninit.get_unchecked_mut() }.as_mut_ptr();
+		unsafe {
+			ptr::addr_of_mut!((*intruded).counter).write(AtomicTipToe::new());
+			ptr::addr_of_mut!((*intruded).value).write(value);
+		}
+	}
+
+	#[test]
+	fn pin_init() {
+		let mut arc = unsafe { Arc::pin_init(|uninit| write(uninit, 1)) };
+		assert_eq!(arc.value, 1);
+
+		// The reference-count is exactly 1.
+		assert!(Arc::get_mut(&mut arc).is_some());
+	}
+
+	#[test]
+	fn try_pin_init_in() {
+		let mut arc = unsafe {
+			Arc::try_pin_init_in(
+				|uninit| {
+					write(uninit, 2);
+					Ok::<_, ()>(())
+				},
+				Global,
+			)
+		}
+		.unwrap();
+		assert_eq!(arc.value, 2);
+		assert!(Arc::get_mut(&mut arc).is_some());
+	}
+
+	#[test]
+	fn try_pin_init_error() {
+		let result = unsafe { Arc::<Intruded>::try_pin_init(|_| Err("failed")) };
+		assert_eq!(result.err(), Some("failed"));
+	}
+
+	#[test]
+	fn try_pin_init_panic() {
+		let result = panic::catch_unwind(AssertUnwindSafe(|| unsafe {
+			Arc::<Intruded>::try_pin_init(|_| -> Result<(), ()> { panic!() })
+		}));
+		assert!(result.is_err());
+	}
+}
@@ -0,0 +1,85 @@
+#![cfg(feature = "sync")]
+
+use allocator_api2::alloc::{Allocator, Layout};
+use core::ptr;
+use tiptoe::{pool::Pool, Arc, AtomicTipToes, IntrusivelyCountable};
+
+#[derive(Debug, Default)]
+struct Intruded {
+	value: usize,
+	counter: AtomicTipToes,
+}
+
+unsafe impl IntrusivelyCountable for Intruded {
+	type RefCounter = AtomicTipToes;
+
+	fn ref_counter(&self) -> &Self::RefCounter {
+		&self.counter
+	}
+}
+
+#[test]
+fn recycles_after_last_drop() {
+	let pool = Pool::<2>::new(Layout::new::<Intruded>());
+
+	let first = Arc::pin_in(Intruded::default(), &pool);
+	let address: *const Intruded = &*first;
+	let clone = first.clone();
+	drop(first);
+
+	let other = Arc::pin_in(
+		Intruded {
+			value: 1,
+			..Default::default()
+		},
+		&pool,
+	);
+	assert!(!ptr::eq(address, &*other));
+
+	drop(clone);
+	let recycled = Arc::pin_in(
+		Intruded {
+			value: 2,
+			..Default::default()
+		},
+		&pool,
+	);
+	assert!(ptr::eq(address, &*recycled));
+	assert_eq!(recycled.value, 2);
+	assert_eq!(other.value, 1);
+}
+
+#[test]
+fn waits_for_weak() {
+	let pool = Pool::<1>::new(Layout::new::<Intruded>());
+
+	let arc = Arc::pin_in(Intruded::default(), &pool);
+	let address: *const Intruded = &*arc;
+	let weak = Arc::downgrade(&arc);
+	drop(arc);
+
+	let other = Arc::new_in(Intruded::default(), &pool);
+	assert!(!ptr::eq(address, &*other));
+
+	// The allocation is recycled only once the last `Weak` is gone, too.
+	drop(weak);
+	drop(other); // The pool is full, so this goes to `Global`.
+	let recycled = Arc::new_in(Intruded::default(), &pool);
+	assert!(ptr::eq(address, &*recycled));
+}
+
+#[test]
+fn overflow_and_foreign_layouts() {
+	let pool = Pool::<1>::new(Layout::new::<Intruded>());
+
+	let a = Arc::new_in(Intruded::default(), &pool);
+	let b = Arc::new_in(Intruded::default(), &pool);
+	drop(a);
+	drop(b); // Overflows to `Global`.
+
+	let foreign = Layout::new::<[u64; 4]>();
+	let allocation = (&pool).allocate(foreign).unwrap();
+	unsafe { (&pool).deallocate(allocation.cast(), foreign) };
+
+	assert_eq!(pool.layout(), Layout::new::<Intruded>());
+}
@@ -0,0 +1,37 @@
+use tiptoe::{IntrusivelyCountable, Rc, TipToe};
+
+#[derive(Debug, Clone, Default)]
+struct Intruded {
+	value: usize,
+	counter: TipToe,
+}
+
+unsafe impl IntrusivelyCountable for Intruded {
+	type RefCounter = TipToe;
+
+	fn ref_counter(&self) -> &Self::RefCounter {
+		&self.counter
+	}
+}
+
+#[test]
+fn try_unwrap() {
+	let a = Rc::new(Intruded::default());
+	let b = a.clone();
+	let a = Rc::try_unwrap(a).unwrap_err();
+	drop(b);
+	assert_eq!(Rc::try_unwrap(a).unwrap().value, 0);
+}
+
+#[test]
+fn make_mut() {
+	let mut a = Rc::pin(Intruded::default());
+	let b = a.clone();
+
+	unsafe { Rc::make_mut(&mut a).as_mut().get_unchecked_mut() }.value = 1;
+	assert_eq!(a.value, 1);
+	assert_eq!(b.value, 0);
+
+	drop(b);
+	assert!(Rc::get_mut(&mut a).is_some());
+}
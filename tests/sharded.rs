@@ -0,0 +1,159 @@
+#![cfg(feature = "std")]
+
+use std::{
+	pin::Pin,
+	sync::{
+		atomic::{AtomicBool, Ordering},
+		Arc as StdArc, Barrier,
+	},
+	thread,
+};
+use tiptoe::{Arc, IntrusivelyCountable, ShardedTipToe};
+
+#[derive(Debug)]
+struct Intruded {
+	dropped: StdArc<AtomicBool>,
+	counter: ShardedTipToe,
+}
+
+unsafe impl IntrusivelyCountable for Intruded {
+	type RefCounter = ShardedTipToe;
+
+	fn ref_counter(&self) -> &Self::RefCounter {
+		&self.counter
+	}
+}
+
+impl Drop for Intruded {
+	fn drop(&mut self) {
+		assert!(!self.dropped.swap(true, Ordering::Relaxed));
+	}
+}
+
+fn intruded() -> (Pin<Arc<Intruded>>, StdArc<AtomicBool>) {
+	let dropped = StdArc::new(AtomicBool::new(false));
+	let arc = Arc::pin(Intruded {
+		dropped: dropped.clone(),
+		counter: ShardedTipToe::new(),
+	});
+	(arc, dropped)
+}
+
+#[test]
+fn collapsed() {
+	let (arc, dropped) = intruded();
+	let clones = (0..10).map(|_| arc.clone()).collect::<Vec<_>>();
+	drop(arc);
+	drop(clones);
+	assert!(dropped.load(Ordering::Relaxed));
+}
+
+#[test]
+fn unused() {
+	let counter = ShardedTipToe::new();
+	assert!(!counter.shard());
+	counter.collapse();
+}
+
+#[test]
+fn shard_and_collapse() {
+	let (arc, dropped) = intruded();
+	assert!(arc.counter.shard());
+	assert!(!arc.counter.shard());
+
+	let clones = (0..10).map(|_| arc.clone()).collect::<Vec<_>>();
+	drop(clones);
+	let clone = arc.clone();
+
+	arc.counter.collapse();
+	arc.counter.collapse();
+	drop(arc);
+	assert!(!dropped.load(Ordering::Relaxed));
+	drop(clone);
+	assert!(dropped.load(Ordering::Relaxed));
+}
+
+#[test]
+fn dropped_while_sharded() {
+	let (arc, dropped) = intruded();
+	assert!(arc.counter.shard());
+
+	let clones = (0..10).map(|_| arc.clone()).collect::<Vec<_>>();
+	let elsewhere = thread::spawn({
+		let clones = (0..10).map(|_| arc.clone()).collect::<Vec<_>>();
+		move || drop(clones)
+	});
+	drop(arc);
+	drop(clones);
+	elsewhere.join().unwrap();
+	assert!(dropped.load(Ordering::Relaxed));
+
+	// The same on a single thread.
+	let (arc, dropped) = intruded();
+	assert!(arc.counter.shard());
+	let clone = arc.clone();
+	drop(arc);
+	drop(clone);
+	assert!(dropped.load(Ordering::Relaxed));
+}
+
+#[test]
+fn get_mut_collapses() {
+	let (mut arc, dropped) = intruded();
+	assert!(arc.counter.shard());
+	let clone = arc.clone();
+	assert!(Arc::get_mut(&mut arc).is_none());
+
+	drop(clone);
+	assert!(Arc::get_mut(&mut arc).is_some());
+
+	// The counter can be sharded again afterwards.
+	assert!(arc.counter.shard());
+	assert!(Arc::get_mut(&mut arc).is_some());
+
+	drop(arc);
+	assert!(dropped.load(Ordering::Relaxed));
+}
+
+#[test]
+fn try_unwrap_collapses() {
+	let (arc, dropped) = intruded();
+	assert!(arc.counter.shard());
+
+	let intruded = Arc::try_unwrap(unsafe { Pin::into_inner_unchecked(arc) }).unwrap();
+	assert!(!dropped.load(Ordering::Relaxed));
+
+	// The counter was reset.
+	assert!(!intruded.counter.shard());
+	drop(intruded);
+	assert!(dropped.load(Ordering::Relaxed));
+}
+
+#[test]
+fn contended() {
+	const THREADS: usize = 4;
+
+	let (arc, dropped) = intruded();
+	assert!(arc.counter.shard());
+	let barrier = &Barrier::new(THREADS + 1);
+
+	thread::scope(|scope| {
+		for _ in 0..THREADS {
+			let arc = arc.clone();
+			scope.spawn(move || {
+				barrier.wait();
+				// Handles are dropped on other threads than they were cloned on.
+				let clones = (0..100).map(|_| arc.clone()).collect::<Vec<_>>();
+				thread::spawn(move || drop(clones)).join().unwrap();
+			});
+		}
+
+		barrier.wait();
+		arc.counter.collapse();
+	});
+	let mut arc = arc;
+	assert!(Arc::get_mut(&mut arc).is_some());
+
+	drop(arc);
+	assert!(dropped.load(Ordering::Relaxed));
+}
@@ -0,0 +1,105 @@
+#![cfg(feature = "sync")]
+
+use allocator_api2::alloc::{AllocError, Allocator, Global, Layout};
+use core::{
+	pin::Pin,
+	ptr::NonNull,
+	sync::atomic::{AtomicIsize, Ordering},
+};
+use tiptoe::{Arc, AtomicTipToe, IntrusivelyCountable, SharedFromThis};
+
+struct Intruded {
+	_nontrivial: Box<usize>,
+	counter: AtomicTipToe,
+}
+
+impl Intruded {
+	fn new() -> Pin<Arc<Self>> {
+		Arc::pin(Self {
+			_nontrivial: Box::new(0),
+			counter: AtomicTipToe::new(),
+		})
+	}
+}
+
+unsafe impl IntrusivelyCountable for Intruded {
+	type RefCounter = AtomicTipToe;
+
+	fn ref_counter(&self) -> &Self::RefCounter {
+		&self.counter
+	}
+}
+
+unsafe impl SharedFromThis for Intruded {}
+
+#[test]
+fn drop_through_shared_from_this() {
+	let a = Intruded::new();
+	let b = a.to_arc();
+	drop(a);
+	drop(b);
+}
+
+#[test]
+fn not_shared() {
+	let unmanaged = Intruded {
+		_nontrivial: Box::new(0),
+		counter: AtomicTipToe::new(),
+	};
+	assert!(unmanaged.try_to_arc().is_none());
+}
+
+#[test]
+fn exclusive() {
+	let mut a = Intruded::new();
+	let exclusive = Arc::get_mut(&mut a).unwrap();
+	assert!(exclusive.try_to_arc().is_none());
+	drop(exclusive);
+	assert!(a.try_to_arc().is_some());
+}
+
+static LIVE: AtomicIsize = AtomicIsize::new(0);
+
+#[derive(Default)]
+struct Counting;
+
+unsafe impl Allocator for Counting {
+	fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+		LIVE.fetch_add(1, Ordering::Relaxed);
+		Global.allocate(layout)
+	}
+
+	unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+		LIVE.fetch_sub(1, Ordering::Relaxed);
+		Global.deallocate(ptr, layout)
+	}
+}
+
+struct InCounting {
+	counter: AtomicTipToe,
+}
+
+unsafe impl IntrusivelyCountable for InCounting {
+	type RefCounter = AtomicTipToe;
+
+	fn ref_counter(&self) -> &Self::RefCounter {
+		&self.counter
+	}
+}
+
+unsafe impl SharedFromThis<Counting> for InCounting {}
+
+#[test]
+fn custom_allocator() {
+	let a = Arc::pin_in(
+		InCounting {
+			counter: AtomicTipToe::new(),
+		},
+		Counting,
+	);
+	let b = a.to_arc();
+	drop(a);
+	assert_eq!(LIVE.load(Ordering::Relaxed), 1);
+	drop(b);
+	assert_eq!(LIVE.load(Ordering::Relaxed), 0);
+}
@@ -0,0 +1,53 @@
+#![cfg(feature = "sync")]
+
+use core::mem::size_of;
+use std::sync::Arc as StdArc;
+use tiptoe::{
+	counted::{Counted, HeaderSlice},
+	sync::ThinArc,
+	Arc, AtomicTipToe,
+};
+
+#[test]
+fn thin() {
+	assert_eq!(size_of::<ThinArc<u8, u128>>(), size_of::<usize>());
+	assert_eq!(size_of::<Option<ThinArc<u8, u128>>>(), size_of::<usize>());
+}
+
+#[test]
+fn deref() {
+	let thin = ThinArc::<_, _>::from_header_and_iter(1_u8, vec![2_u128, 3]);
+	assert_eq!(thin.header, 1);
+	assert_eq!(&thin.slice, &[2, 3]);
+
+	let empty = ThinArc::<_, u128>::from_header_and_iter((), None);
+	assert!(empty.slice.is_empty());
+}
+
+#[test]
+fn round_trip() {
+	let thin = ThinArc::<_, _>::from_header_and_iter("header", 0..3);
+	let clone = thin.clone();
+	let address: *const HeaderSlice<_, _> = &*thin;
+
+	let fat: Arc<Counted<HeaderSlice<&str, [i32]>, AtomicTipToe>> = thin.into();
+	assert!(core::ptr::eq(address, &**fat));
+	assert_eq!(&fat.slice, &[0, 1, 2]);
+
+	let thin = ThinArc::from(fat);
+	assert!(ThinArc::ptr_eq(&thin, &clone));
+	assert_eq!(thin, clone);
+}
+
+#[test]
+fn drops() {
+	let counter = StdArc::new(());
+	let thin = ThinArc::<_, _>::from_header_and_iter(counter.clone(), vec![counter.clone(); 3]);
+	let clone = thin.clone();
+	assert_eq!(StdArc::strong_count(&counter), 5);
+
+	drop(thin);
+	assert_eq!(StdArc::strong_count(&counter), 5);
+	drop(clone);
+	assert_eq!(StdArc::strong_count(&counter), 1);
+}
@@ -0,0 +1,57 @@
+use std::panic::{self, AssertUnwindSafe};
+use tiptoe::{
+	set_exclusivity_violation_handler, ExclusivityViolation, IntrusivelyCountable, Rc, TipToe,
+};
+
+#[derive(Debug, Default)]
+struct Intruded {
+	counter: TipToe,
+}
+
+unsafe impl IntrusivelyCountable for Intruded {
+	type RefCounter = TipToe;
+
+	fn ref_counter(&self) -> &Self::RefCounter {
+		&self.counter
+	}
+}
+
+#[test]
+fn try_clone() {
+	let mut rc = Rc::pin(Intruded::default());
+
+	{
+		let exclusive = Rc::get_mut(&mut rc).unwrap();
+		let inner: &Intruded = &exclusive;
+		let borrowed = unsafe { Rc::borrow_pin_from_inner_ref(&inner) };
+		assert!(Rc::try_clone_pinned(borrowed).is_err());
+	}
+
+	let clone = Rc::try_clone_pinned(&rc).unwrap();
+	assert!(Rc::get_mut(&mut rc).is_none());
+	drop(clone);
+	assert!(Rc::get_mut(&mut rc).is_some());
+}
+
+#[test]
+fn custom_handler() {
+	fn handler(violation: ExclusivityViolation) -> ! {
+		panic!("Custom handler: {}", violation)
+	}
+	set_exclusivity_violation_handler(handler);
+
+	let mut rc = Rc::pin(Intruded::default());
+	let exclusive = Rc::get_mut(&mut rc).unwrap();
+	let inner: &Intruded = &exclusive;
+	let borrowed = unsafe { Rc::borrow_pin_from_inner_ref(&inner) };
+
+	let payload = panic::catch_unwind(AssertUnwindSafe(|| borrowed.clone())).unwrap_err();
+	assert!(payload
+		.downcast_ref::<String>()
+		.unwrap()
+		.starts_with("Custom handler: "));
+
+	// The failed clone didn't change the reference count.
+	drop(exclusive);
+	assert!(Rc::get_mut(&mut rc).is_some());
+}
@@ -0,0 +1,132 @@
+use tiptoe::{counted::Counted, IntrusivelyCountable, Rc, TipToe};
+
+#[derive(Debug, Default)]
+struct Intruded {
+	value: usize,
+	counter: TipToe,
+}
+
+unsafe impl IntrusivelyCountable for Intruded {
+	type RefCounter = TipToe;
+
+	fn ref_counter(&self) -> &Self::RefCounter {
+		&self.counter
+	}
+}
+
+#[test]
+fn rc_try_new() {
+	let rc = Rc::try_new(Intruded {
+		value: 1,
+		..Intruded::default()
+	})
+	.unwrap();
+	let clone = rc.clone();
+	drop(rc);
+	assert_eq!(Rc::try_unwrap(clone).unwrap().value, 1);
+}
+
+#[test]
+fn rc_try_pin() {
+	let mut rc = Rc::try_pin(Intruded::default()).unwrap();
+	assert!(Rc::get_mut(&mut rc).is_some());
+}
+
+#[test]
+fn counted_try_from_exact_iter() {
+	let counted = Counted::<[u8]>::try_from_exact_iter(vec![1, 2, 3]).unwrap();
+	assert_eq!(&**counted, &[1, 2, 3]);
+}
+
+#[test]
+fn counted_try_from_header_and_iter() {
+	let counted = Counted::<_>::try_from_header_and_iter("header", 0..3_u8).unwrap();
+	assert_eq!(counted.header, "header");
+	assert_eq!(&counted.slice, &[0, 1, 2]);
+}
+
+#[cfg(feature = "sync")]
+mod sync {
+	use allocator_api2::alloc::{AllocError, Allocator, Layout};
+	use core::ptr::NonNull;
+	use tiptoe::{sync::ThinArc, Arc, AtomicTipToe, IntrusivelyCountable};
+
+	struct Exhausted;
+
+	unsafe impl Allocator for Exhausted {
+		fn allocate(&self, _: Layout) -> Result<NonNull<[u8]>, AllocError> {
+			Err(AllocError)
+		}
+
+		unsafe fn deallocate(&self, _: NonNull<u8>, _: Layout) {
+			unreachable!()
+		}
+	}
+
+	#[derive(Debug, Default)]
+	struct Intruded {
+		value: usize,
+		counter: AtomicTipToe,
+	}
+
+	unsafe impl IntrusivelyCountable for Intruded {
+		type RefCounter = AtomicTipToe;
+
+		fn ref_counter(&self) -> &Self::RefCounter {
+			&self.counter
+		}
+	}
+
+	#[test]
+	fn try_new() {
+		let arc = Arc::try_new(Intruded {
+			value: 1,
+			..Intruded::default()
+		})
+		.unwrap();
+		let clone = arc.clone();
+		drop(arc);
+		assert_eq!(Arc::try_unwrap(clone).unwrap().value, 1);
+	}
+
+	#[test]
+	fn try_pin() {
+		let mut arc = Arc::try_pin(Intruded::default()).unwrap();
+		assert!(Arc::get_mut(&mut arc).is_some());
+	}
+
+	#[test]
+	fn try_new_in_hands_back_value() {
+		let error = Arc::try_new_in(
+			Intruded {
+				value: 2,
+				..Intruded::default()
+			},
+			Exhausted,
+		)
+		.err()
+		.unwrap();
+		assert_eq!(error.to_string(), "Memory allocation failed.");
+
+		let value = error.into_inner();
+		assert_eq!(value.value, 2);
+
+		// The reference-count wasn't touched.
+		assert_eq!(Arc::try_unwrap(Arc::new(value)).unwrap().value, 2);
+	}
+
+	#[test]
+	fn try_pin_in_hands_back_value() {
+		let error = Arc::try_pin_in(Intruded::default(), Exhausted)
+			.err()
+			.unwrap();
+		assert_eq!(error.into_inner().value, 0);
+	}
+
+	#[test]
+	fn thin_arc_try_from_header_and_iter() {
+		let thin = ThinArc::<_, _>::try_from_header_and_iter("header", 0..3_u8).unwrap();
+		assert_eq!(thin.header, "header");
+		assert_eq!(&thin.slice, &[0, 1, 2]);
+	}
+}
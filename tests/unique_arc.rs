@@ -0,0 +1,62 @@
+#![cfg(feature = "sync")]
+
+use core::pin::Pin;
+use tiptoe::{Arc, AtomicTipToes, IntrusivelyCountable, UniqueArc};
+
+#[derive(Debug, Default)]
+struct Intruded {
+	value: usize,
+	counter: AtomicTipToes,
+}
+
+unsafe impl IntrusivelyCountable for Intruded {
+	type RefCounter = AtomicTipToes;
+
+	fn ref_counter(&self) -> &Self::RefCounter {
+		&self.counter
+	}
+}
+
+impl Intruded {
+	fn increment(self: Pin<&mut Self>) {
+		unsafe { self.get_unchecked_mut() }.value += 1
+	}
+}
+
+#[test]
+fn shareable() {
+	let mut unique = UniqueArc::new(Intruded::default());
+	unique.value = 2;
+	let address: *const Intruded = &*unique;
+
+	let arc = UniqueArc::shareable(unique);
+	assert!(core::ptr::eq(address, &*arc));
+	assert_eq!(arc.value, 2);
+
+	let clone = arc.clone();
+	drop(arc);
+	assert_eq!(Arc::try_unwrap(clone).unwrap().value, 2);
+}
+
+#[test]
+fn shareable_pinned() {
+	let mut unique = UniqueArc::pin(Intruded::default());
+	unique.as_mut().increment();
+	unique.as_mut().increment();
+
+	let mut arc: Pin<Arc<_>> = unique.into();
+	let weak = Arc::downgrade(&arc);
+	assert_eq!(Arc::get_mut(&mut arc).unwrap().value, 2);
+	drop(arc);
+	assert!(weak.upgrade().is_none());
+}
+
+#[test]
+fn drop_unshared() {
+	let unique = UniqueArc::new(Intruded::default());
+	drop(unique);
+	assert_eq!(
+		UniqueArc::into_inner(UniqueArc::new(Intruded::default())).value,
+		0
+	);
+}
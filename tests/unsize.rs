@@ -0,0 +1,127 @@
+use core::{cell::Cell, pin::Pin};
+use tiptoe::{unsize, IntrusivelyCountable, Rc, TipToe};
+
+trait Named: IntrusivelyCountable<RefCounter = TipToe> {
+	fn name(&self) -> &str;
+}
+
+struct Intruded<'a> {
+	counter: TipToe,
+	name: &'static str,
+	_padding: [u64; 3],
+	drops: &'a Cell<usize>,
+}
+
+impl<'a> Intruded<'a> {
+	fn new(name: &'static str, drops: &'a Cell<usize>) -> Self {
+		Self {
+			counter: TipToe::new(),
+			name,
+			_padding: [0; 3],
+			drops,
+		}
+	}
+}
+
+impl Drop for Intruded<'_> {
+	fn drop(&mut self) {
+		self.drops.set(self.drops.get() + 1)
+	}
+}
+
+unsafe impl IntrusivelyCountable for Intruded<'_> {
+	type RefCounter = TipToe;
+
+	fn ref_counter(&self) -> &Self::RefCounter {
+		&self.counter
+	}
+}
+
+impl Named for Intruded<'_> {
+	fn name(&self) -> &str {
+		self.name
+	}
+}
+
+#[test]
+fn rc() {
+	let drops = Cell::new(0);
+
+	let rc = Rc::new(Intruded::new("unpinned", &drops));
+	let clone = rc.clone();
+	let named: Rc<dyn Named + '_> = unsize!(rc => dyn Named);
+	assert_eq!(named.name(), "unpinned");
+	assert!(core::ptr::eq(
+		(&*named as *const dyn Named).cast::<()>(),
+		(&*clone as *const Intruded<'_>).cast::<()>()
+	));
+
+	drop(clone);
+	assert_eq!(drops.get(), 0);
+	drop(named);
+	assert_eq!(drops.get(), 1);
+}
+
+#[test]
+fn rc_pinned() {
+	let drops = Cell::new(0);
+
+	let rc = Rc::pin(Intruded::new("pinned", &drops));
+	let named: Pin<Rc<dyn Named + '_>> = unsize!(rc => dyn Named);
+	assert_eq!(named.name(), "pinned");
+
+	drop(named);
+	assert_eq!(drops.get(), 1);
+}
+
+#[cfg(feature = "sync")]
+#[test]
+fn arc() {
+	use tiptoe::{Arc, AtomicTipToe};
+
+	trait Value: IntrusivelyCountable<RefCounter = AtomicTipToe> + Send + Sync {
+		fn value(&self) -> usize;
+	}
+
+	struct Atomic {
+		counter: AtomicTipToe,
+		value: usize,
+	}
+
+	unsafe impl IntrusivelyCountable for Atomic {
+		type RefCounter = AtomicTipToe;
+
+		fn ref_counter(&self) -> &Self::RefCounter {
+			&self.counter
+		}
+	}
+
+	impl Value for Atomic {
+		fn value(&self) -> usize {
+			self.value
+		}
+	}
+
+	let arc = Arc::pin(Atomic {
+		counter: AtomicTipToe::new(),
+		value: 5,
+	});
+	let value: Pin<Arc<dyn Value>> = unsize!(arc => dyn Value);
+	let clone = value.clone();
+	std::thread::spawn(move || assert_eq!(clone.value(), 5))
+		.join()
+		.unwrap();
+	assert_eq!(value.value(), 5);
+}
+
+#[cfg(feature = "nightly")]
+#[test]
+fn coerce_unsized() {
+	let drops = Cell::new(0);
+
+	let named: Pin<Rc<dyn Named + '_>> = Rc::pin(Intruded::new("coerced", &drops));
+	assert_eq!(named.name(), "coerced");
+
+	drop(named);
+	assert_eq!(drops.get(), 1);
+}
@@ -0,0 +1,60 @@
+#![cfg(feature = "sync")]
+
+use core::{
+	pin::Pin,
+	sync::atomic::{AtomicUsize, Ordering},
+	task::Waker,
+};
+use tiptoe::{sync::IntrusiveWake, Arc, AtomicTipToe, IntrusivelyCountable};
+
+#[derive(Debug, Default)]
+struct Task {
+	counter: AtomicTipToe,
+	woken: AtomicUsize,
+}
+
+unsafe impl IntrusivelyCountable for Task {
+	type RefCounter = AtomicTipToe;
+
+	fn ref_counter(&self) -> &Self::RefCounter {
+		&self.counter
+	}
+}
+
+impl IntrusiveWake for Task {
+	fn wake(this: Pin<Arc<Self>>) {
+		this.woken.fetch_add(1, Ordering::Relaxed);
+	}
+}
+
+#[test]
+fn waker() {
+	let mut task = Arc::pin(Task::default());
+
+	let waker = Arc::into_waker(task.clone());
+	let clone = waker.clone();
+	assert!(Arc::get_mut(&mut task).is_none());
+
+	waker.wake_by_ref();
+	assert_eq!(task.woken.load(Ordering::Relaxed), 1);
+
+	waker.wake();
+	assert_eq!(task.woken.load(Ordering::Relaxed), 2);
+	assert!(Arc::get_mut(&mut task).is_none());
+
+	drop(clone);
+	assert_eq!(task.woken.load(Ordering::Relaxed), 2);
+
+	// All references were released.
+	assert!(Arc::get_mut(&mut task).is_some());
+}
+
+#[test]
+fn from() {
+	let mut task = Arc::pin(Task::default());
+
+	let waker = Waker::from(task.clone());
+	waker.wake();
+	assert_eq!(task.woken.load(Ordering::Relaxed), 1);
+	assert!(Arc::get_mut(&mut task).is_some());
+}
@@ -0,0 +1,99 @@
+use core::cell::Cell;
+use tiptoe::{rc, IntrusivelyCountable, Rc, TipToes};
+
+struct Intruded<'a> {
+	drops: &'a Cell<usize>,
+	counter: TipToes,
+}
+
+impl Drop for Intruded<'_> {
+	fn drop(&mut self) {
+		self.drops.set(self.drops.get() + 1)
+	}
+}
+
+unsafe impl IntrusivelyCountable for Intruded<'_> {
+	type RefCounter = TipToes;
+
+	fn ref_counter(&self) -> &Self::RefCounter {
+		&self.counter
+	}
+}
+
+#[test]
+fn upgrade() {
+	let drops = &Cell::new(0);
+	let strong = Rc::pin(Intruded {
+		drops,
+		counter: TipToes::new(),
+	});
+	let weak = Rc::downgrade(&strong);
+
+	let upgraded = weak.upgrade().unwrap();
+	drop(strong);
+	assert_eq!(drops.get(), 0);
+
+	drop(upgraded);
+	assert_eq!(drops.get(), 1);
+	assert!(weak.upgrade().is_none());
+
+	let weak_2 = weak.clone();
+	assert!(rc::Weak::ptr_eq(&weak, &weak_2));
+	drop(weak);
+	drop(weak_2);
+	assert_eq!(drops.get(), 1);
+}
+
+#[test]
+fn weak_blocks_exclusivity_only_while_upgraded() {
+	let drops = &Cell::new(0);
+	let mut strong = Rc::pin(Intruded {
+		drops,
+		counter: TipToes::new(),
+	});
+	let weak = Rc::downgrade(&strong);
+	assert!(Rc::get_mut(&mut strong).is_some());
+
+	let upgraded = weak.upgrade().unwrap();
+	assert!(Rc::get_mut(&mut strong).is_none());
+	drop(upgraded);
+
+	drop(strong);
+	assert_eq!(drops.get(), 1);
+	assert!(weak.upgrade().is_none());
+}
+
+#[test]
+fn upgrade_during_exclusivity() {
+	let drops = &Cell::new(0);
+	let mut strong = Rc::pin(Intruded {
+		drops,
+		counter: TipToes::new(),
+	});
+	let weak = Rc::downgrade(&strong);
+
+	let exclusive = Rc::get_mut(&mut strong).unwrap();
+	assert!(weak.upgrade().is_none());
+	drop(exclusive);
+	assert!(weak.upgrade().is_some());
+
+	drop(strong);
+	assert_eq!(drops.get(), 1);
+}
+
+#[cfg(feature = "sync")]
+#[test]
+fn sync() {
+	use tiptoe::Arc;
+
+	let drops = &Cell::new(0);
+	let strong = Arc::pin(Intruded {
+		drops,
+		counter: TipToes::new(),
+	});
+	let weak = Arc::downgrade(&strong);
+	assert!(weak.upgrade().is_some());
+	drop(strong);
+	assert_eq!(drops.get(), 1);
+	assert!(weak.upgrade().is_none());
+}